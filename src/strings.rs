@@ -1,5 +1,24 @@
 //! This modules contains the trait that define new methods for `String` and &str
+//!
+//! Every trait is implemented for all the types that implement `AsRef<str>`, so the methods
+//! are also available on `Cow<'_, str>`, `Box<str>`, `Rc<str>` and `Arc<str>` without
+//! allocating a new `String` first
+mod escaping;
+mod fuzzy;
+mod globbing;
+mod identifiers;
+mod inflections;
+mod locale;
 pub mod methods;
+mod natural;
+mod similarity;
+mod splitting;
+mod templates;
+mod transliteration;
+mod unicode;
+mod validation;
+mod words;
+mod wrapping;
 
 #[cfg(test)]
 mod tests {
@@ -14,6 +33,70 @@ mod tests {
         assert_eq!("thisisanothertestwithnormal", case2.remove_whitespaces());
     }
 
+    #[test]
+    fn remove_whitespaces_except_newlines() {
+        use crate::strings::methods::RemoveWhitespaces;
+
+        let case1 = " first line \r\n\tsecond line \n";
+
+        assert_eq!(
+            "firstline\r\nsecondline\n",
+            case1.remove_whitespaces_except_newlines()
+        );
+    }
+
+    #[test]
+    fn collapse_whitespace() {
+        use crate::strings::methods::RemoveWhitespaces;
+
+        assert_eq!(
+            "first case with spaces",
+            "first   case\twith \n spaces".collapse_whitespace()
+        );
+        assert_eq!(" padded ", "   padded  ".collapse_whitespace());
+        assert_eq!("no_spaces", "no_spaces".collapse_whitespace());
+        assert_eq!("a b", "a\u{3000}\u{A0}b".collapse_whitespace());
+    }
+
+    #[test]
+    fn trim_lines() {
+        use crate::strings::methods::RemoveWhitespaces;
+
+        assert_eq!(
+            "first\nsecond\r\nthird",
+            "  first  \n\tsecond \r\n third".trim_lines()
+        );
+        assert_eq!("\n\nlast\n", "  \n \n last \n".trim_lines());
+        assert_eq!("", "".trim_lines());
+    }
+
+    #[test]
+    fn normalize_line_endings() {
+        use crate::strings::methods::RemoveWhitespaces;
+
+        assert_eq!(
+            "first\nsecond\nthird\n",
+            "first\r\nsecond\rthird\n".normalize_line_endings()
+        );
+        assert_eq!("\n\n", "\r\r".normalize_line_endings());
+        assert_eq!("\n\n", "\r\n\r\n".normalize_line_endings());
+    }
+
+    #[test]
+    fn strip_zero_width() {
+        use crate::strings::methods::RemoveWhitespaces;
+
+        assert_eq!(
+            "username",
+            "\u{FEFF}user\u{200B}name\u{2060}".strip_zero_width()
+        );
+        assert_eq!(
+            "a\u{200B}b".len() - 3,
+            "a\u{200B}b".strip_zero_width().len()
+        );
+        assert_eq!("👩💻", "👩\u{200D}💻".strip_zero_width());
+    }
+
     #[test]
     fn to_snake_case() {
         use crate::strings::methods::ToCases;
@@ -58,6 +141,354 @@ mod tests {
         assert_eq!("ThisIsAThirdCase3", case3.to_pascal_case());
     }
 
+    #[test]
+    fn split_words_works() {
+        use crate::strings::methods::CaseOptions;
+        use crate::strings::words::split_words;
+
+        let options = CaseOptions::new();
+
+        let case1: Vec<&str> = split_words("parseHttpResponse", &options).collect();
+        assert_eq!(vec!["parse", "Http", "Response"], case1);
+
+        let case2: Vec<&str> = split_words("HTTPServer_url-v2.final", &options).collect();
+        assert_eq!(vec!["HTTP", "Server", "url", "v2", "final"], case2);
+
+        let case3: Vec<&str> = split_words("  Vec3Point  x__y ", &options).collect();
+        assert_eq!(vec!["Vec3", "Point", "x", "y"], case3);
+
+        assert_eq!(split_words(" _-. ", &options).next(), None);
+    }
+
+    #[test]
+    fn to_cases_convert_between_cases() {
+        use crate::strings::methods::ToCases;
+
+        assert_eq!(
+            "parse_http_response",
+            String::from("parseHttpResponse").to_snake_case()
+        );
+        assert_eq!(
+            "http_server_url",
+            String::from("HTTPServerURL").to_snake_case()
+        );
+        assert_eq!("userId", String::from("user_id").to_camel_case());
+        assert_eq!("userId", String::from("UserId").to_camel_case());
+        assert_eq!("ContentType", String::from("content-type").to_pascal_case());
+        assert_eq!(
+            "AppConfigName",
+            String::from("app.config.name").to_pascal_case()
+        );
+        assert_eq!("", String::from(" _ ").to_camel_case());
+    }
+
+    #[test]
+    fn to_cases_non_ascii_corpus() {
+        use crate::strings::methods::ToCases;
+
+        let corpus = [
+            ("élan vital", "élan_vital", "élanVital", "ÉlanVital"),
+            ("über_größe", "über_größe", "überGröße", "ÜberGröße"),
+            ("ÑandúRápido", "ñandú_rápido", "ñandúRápido", "ÑandúRápido"),
+            ("straße ßig", "straße_ßig", "straßeSsig", "StraßeSsig"),
+            (
+                "e\u{301}cole ÉTÉ",
+                "e\u{301}cole_été",
+                "e\u{301}coleÉté",
+                "E\u{301}coleÉté",
+            ),
+            ("ǆungla ǉubav", "ǆungla_ǉubav", "ǆunglaǈubav", "ǅunglaǈubav"),
+            ("ΟΔΟΣ ΚΑΛΟΣ", "οδος_καλος", "οδοςΚαλος", "ΟδοςΚαλος"),
+            ("ﬁle naïve", "ﬁle_naïve", "ﬁleNaïve", "FileNaïve"),
+            (
+                "日本語 テキスト",
+                "日本語_テキスト",
+                "日本語テキスト",
+                "日本語テキスト",
+            ),
+            ("नमस्ते दुनिया", "नमस्ते_दुनिया", "नमस्तेदुनिया", "नमस्तेदुनिया"),
+            ("வணக்கம் உலகம்", "வணக்கம்_உலகம்", "வணக்கம்உலகம்", "வணக்கம்உலகம்"),
+            ("שָׁלוֹם עוֹלָם", "שָׁלוֹם_עוֹלָם", "שָׁלוֹםעוֹלָם", "שָׁלוֹםעוֹלָם"),
+        ];
+
+        for (input, snake, camel, pascal) in corpus {
+            let input = String::from(input);
+            assert_eq!(snake, input.to_snake_case(), "snake case of {input}");
+            assert_eq!(camel, input.to_camel_case(), "camel case of {input}");
+            assert_eq!(pascal, input.to_pascal_case(), "pascal case of {input}");
+        }
+    }
+
+    #[test]
+    fn combining_marks_of_indic_scripts() {
+        use crate::strings::methods::{IsCases, SelectNth};
+
+        assert!("அம்மா".is_snake_case());
+        assert!("नमस्ते_दुनिया".is_snake_case());
+        assert_eq!(Some("स्"), "नमस्ते".nth(2));
+        assert_eq!(Some("ते"), "नमस्ते".nth(3));
+        assert_eq!(None, "नमस्ते".nth(4));
+    }
+
+    #[test]
+    fn to_kebab_case() {
+        use crate::strings::methods::ToCases;
+
+        assert_eq!(
+            "this-is-the-first-case",
+            String::from("this is the first case").to_kebab_case()
+        );
+        assert_eq!("dry-run", String::from("dryRun").to_kebab_case());
+        assert_eq!(
+            "max-http-retries2",
+            String::from("MAX_HTTP_RETRIES2").to_kebab_case()
+        );
+    }
+
+    #[test]
+    fn to_screaming_snake_case() {
+        use crate::strings::methods::ToCases;
+
+        assert_eq!(
+            "DATABASE_URL",
+            String::from("databaseUrl").to_screaming_snake_case()
+        );
+        assert_eq!(
+            "STRASSE_NAME",
+            String::from("straße name").to_screaming_snake_case()
+        );
+        assert_eq!(
+            "API_V2_KEY",
+            String::from("api-v2-key").to_screaming_snake_case()
+        );
+    }
+
+    #[test]
+    fn to_train_case() {
+        use crate::strings::methods::ToCases;
+
+        assert_eq!("Content-Type", String::from("content_type").to_train_case());
+        assert_eq!("X-Request-Id", String::from("xRequestId").to_train_case());
+    }
+
+    #[test]
+    fn to_dot_and_path_case() {
+        use crate::strings::methods::ToCases;
+
+        let case1 = String::from("ServerMaxConnections");
+
+        assert_eq!("server.max.connections", case1.to_dot_case());
+        assert_eq!("server/max/connections", case1.to_path_case());
+    }
+
+    #[test]
+    fn to_title_and_sentence_case() {
+        use crate::strings::methods::ToCases;
+
+        let case1 = String::from("user_account_settings");
+
+        assert_eq!("User Account Settings", case1.to_title_case());
+        assert_eq!("User account settings", case1.to_sentence_case());
+
+        let case2 = String::from("  über  GRÖSSE ");
+
+        assert_eq!("Über Grösse", case2.to_title_case());
+        assert_eq!("Über grösse", case2.to_sentence_case());
+    }
+
+    #[test]
+    fn convert_case_works() {
+        use crate::strings::methods::{Case, ToCases};
+
+        let case1 = String::from("userAccount");
+
+        assert_eq!("user-account", case1.convert_case(Case::Kebab));
+        assert_eq!("USER_ACCOUNT", case1.convert_case(Case::ScreamingSnake));
+        assert_eq!("User Account", case1.convert_case(Case::Title));
+
+        for case in Case::ALL {
+            assert_eq!(case, case.name().parse().unwrap());
+        }
+
+        assert_eq!(Ok(Case::Kebab), "kebab".parse());
+        assert_eq!(Ok(Case::ScreamingSnake), "SCREAMING_SNAKE_CASE".parse());
+        assert_eq!(Ok(Case::Camel), "lowerCamelCase".parse());
+        assert!("shouting".parse::<Case>().is_err());
+    }
+
+    #[test]
+    fn convert_case_with_acronyms() {
+        use crate::strings::methods::{CapitalRuns, Case, CaseOptions, ToCases};
+
+        let case1 = String::from("xml http request");
+        let options = CaseOptions::new().acronyms(["xml", "HTTP"]);
+
+        assert_eq!("XmlHttpRequest", case1.to_pascal_case());
+        assert_eq!(
+            "XMLHTTPRequest",
+            case1.convert_case_with(Case::Pascal, &options)
+        );
+        assert_eq!(
+            "xmlHTTPRequest",
+            case1.convert_case_with(Case::Camel, &options)
+        );
+        assert_eq!(
+            "XML-HTTP-Request",
+            case1.convert_case_with(Case::Train, &options)
+        );
+
+        let case2 = String::from("XMLHTTPRequest");
+
+        assert_eq!("xmlhttp_request", case2.to_snake_case());
+        assert_eq!(
+            "xml_http_request",
+            case2.convert_case_with(Case::Snake, &options)
+        );
+
+        let case3 = String::from("HTTPServerURL");
+
+        assert_eq!("http_server_url", case3.to_snake_case());
+        assert_eq!(
+            "HTTPServerURL",
+            case3.convert_case_with(Case::Pascal, &CaseOptions::new().acronyms(["HTTP", "URL"]))
+        );
+
+        let options = CaseOptions::new().acronyms(["HTTP", "HTTPS"]);
+
+        assert_eq!(
+            "https_connection",
+            String::from("HTTPSConnection").convert_case_with(Case::Snake, &options)
+        );
+
+        let options = CaseOptions::new().acronym("ID");
+
+        assert_eq!("ids", "IDS".convert_case_with(Case::Snake, &options));
+        assert_eq!("ids", "IDS".convert_case_with(Case::Camel, &options));
+        assert_eq!(
+            "ids_server",
+            "IDSServer".convert_case_with(Case::Snake, &options)
+        );
+        assert_eq!(
+            "user_ids",
+            "userIDs".convert_case_with(Case::Snake, &options)
+        );
+        assert_eq!("user_i_ds", "userIDs".to_snake_case());
+        assert_eq!(
+            "userIDs",
+            "user_ids".convert_case_with(Case::Camel, &options)
+        );
+        assert_eq!(
+            "UserIDsList",
+            "userIDsList".convert_case_with(Case::Pascal, &options)
+        );
+        assert_eq!("ID", "id".convert_case_with(Case::Pascal, &options));
+
+        let options = CaseOptions::new().acronyms(["XML", "ID"]);
+
+        assert_eq!("xml_ids", "XMLIDs".convert_case_with(Case::Snake, &options));
+        assert_eq!(
+            "xml_http2",
+            "XMLHTTP2".convert_case_with(Case::Snake, &options.acronym("HTTP"))
+        );
+
+        let options = CaseOptions::new().capital_runs(CapitalRuns::EveryCapital);
+
+        assert_eq!(
+            "i_o_stream",
+            String::from("IOStream").convert_case_with(Case::Snake, &options)
+        );
+    }
+
+    #[test]
+    fn write_and_display_cases() {
+        use crate::strings::methods::{Case, CaseOptions, ToCases};
+        use std::fmt::Write;
+
+        let mut output = String::new();
+
+        "parseHttpResponse".write_snake_case(&mut output).unwrap();
+        output.push(',');
+        "user_id".write_camel_case(&mut output).unwrap();
+        output.push(',');
+        "content type".write_train_case(&mut output).unwrap();
+        output.push(',');
+        "ΟΔΟΣ ΚΑΛΟΣ".write_case(Case::Title, &mut output).unwrap();
+
+        assert_eq!("parse_http_response,userId,Content-Type,Οδος Καλος", output);
+
+        output.clear();
+        let options = CaseOptions::new().acronyms(["XML", "HTTP"]);
+        "xml http request"
+            .write_case_with(Case::Pascal, &options, &mut output)
+            .unwrap();
+        assert_eq!("XMLHTTPRequest", output);
+
+        assert_eq!("user-id", "userId".as_kebab_case().to_string());
+        assert_eq!("USER_ID", format!("{}", "userId".as_screaming_snake_case()));
+        assert_eq!("[user.id]", format!("[{}]", "UserId".as_dot_case()));
+        assert_eq!(
+            "UserID",
+            "user id"
+                .as_case_with(Case::Pascal, &CaseOptions::new().acronym("id"))
+                .to_string()
+        );
+
+        let mut line = String::new();
+        write!(
+            line,
+            "{}/{}",
+            "UserAccount".as_path_case(),
+            "straße".as_screaming_snake_case()
+        )
+        .unwrap();
+        assert_eq!("user/account/STRASSE", line);
+    }
+
+    #[test]
+    fn detect_case_works() {
+        use crate::strings::methods::{Case, IsCases};
+
+        assert_eq!(
+            Some(Case::Snake),
+            String::from("user_account").detect_case()
+        );
+        assert_eq!(
+            Some(Case::Kebab),
+            String::from("user-account").detect_case()
+        );
+        assert_eq!(
+            Some(Case::ScreamingSnake),
+            String::from("USER_ACCOUNT").detect_case()
+        );
+        assert_eq!(
+            Some(Case::Camel),
+            String::from("userAccount2").detect_case()
+        );
+        assert_eq!(
+            Some(Case::Pascal),
+            String::from("UserAccount").detect_case()
+        );
+        assert_eq!(
+            Some(Case::Train),
+            String::from("User-Account").detect_case()
+        );
+        assert_eq!(Some(Case::Dot), String::from("user.account").detect_case());
+        assert_eq!(Some(Case::Path), String::from("user/account").detect_case());
+        assert_eq!(
+            Some(Case::Sentence),
+            String::from("User account").detect_case()
+        );
+        assert_eq!(
+            Some(Case::Title),
+            String::from("User Account").detect_case()
+        );
+        assert_eq!(Some(Case::Snake), String::from("user").detect_case());
+
+        assert_eq!(None, String::from("user Account").detect_case());
+        assert_eq!(None, String::from("user__account").detect_case());
+        assert_eq!(None, String::new().detect_case());
+    }
+
     #[test]
     fn is_camel_case_works() {
         use crate::strings::methods::IsCases;
@@ -110,6 +541,1437 @@ mod tests {
         assert!(!case6.is_pascal_case());
     }
 
+    #[test]
+    fn is_cases_allow_digits() {
+        use crate::strings::methods::IsCases;
+
+        assert!(String::from("userId2").is_camel_case());
+        assert!(String::from("Vec3").is_pascal_case());
+        assert!(!String::from("3dModel").is_camel_case());
+        assert!(!String::from("2Fast").is_pascal_case());
+    }
+
+    #[test]
+    fn is_snake_case_works() {
+        use crate::strings::methods::IsCases;
+
+        assert!(String::from("user_id2").is_snake_case());
+        assert!(String::from("größe").is_snake_case());
+        assert!(!String::from("user__id").is_snake_case());
+        assert!(!String::from("_user_id").is_snake_case());
+        assert!(!String::from("user_id_").is_snake_case());
+        assert!(!String::from("User_id").is_snake_case());
+        assert!(!String::from("user id").is_snake_case());
+        assert!(!String::from("user-id").is_snake_case());
+        assert!(!String::new().is_snake_case());
+    }
+
+    #[test]
+    fn is_kebab_case_works() {
+        use crate::strings::methods::IsCases;
+
+        assert!(String::from("dry-run").is_kebab_case());
+        assert!(String::from("http2-only").is_kebab_case());
+        assert!(!String::from("dry_run").is_kebab_case());
+        assert!(!String::from("Dry-run").is_kebab_case());
+    }
+
+    #[test]
+    fn is_screaming_snake_case_works() {
+        use crate::strings::methods::IsCases;
+
+        assert!(String::from("MAX_RETRIES_3").is_screaming_snake_case());
+        assert!(String::from("URL").is_screaming_snake_case());
+        assert!(!String::from("MAX_retries").is_screaming_snake_case());
+        assert!(!String::from("MAX-RETRIES").is_screaming_snake_case());
+    }
+
+    #[test]
+    fn is_train_case_works() {
+        use crate::strings::methods::IsCases;
+
+        assert!(String::from("Content-Type").is_train_case());
+        assert!(String::from("X-Api-V2").is_train_case());
+        assert!(!String::from("Content-type").is_train_case());
+        assert!(!String::from("Content-TYPE").is_train_case());
+        assert!(!String::from("content-Type").is_train_case());
+    }
+
+    #[test]
+    fn is_case_digit_rules() {
+        use crate::strings::methods::{Case, DigitRule, IsCases};
+
+        let case1 = String::from("api_2_key");
+
+        assert!(case1.is_case(Case::Snake, DigitRule::NotLeading));
+        assert!(!case1.is_case(Case::Snake, DigitRule::NotWordStart));
+        assert!(!case1.is_case(Case::Snake, DigitRule::Forbidden));
+
+        let case2 = String::from("3d_model");
+
+        assert!(!case2.is_case(Case::Snake, DigitRule::NotLeading));
+        assert!(case2.is_case(Case::Snake, DigitRule::Anywhere));
+
+        let case3 = String::from("userId2");
+
+        assert!(case3.is_case(Case::Camel, DigitRule::NotWordStart));
+        assert!(!case3.is_case(Case::Camel, DigitRule::Forbidden));
+    }
+
+    #[test]
+    fn validate_case_works() {
+        use crate::strings::methods::{Case, CaseRule, IsCases};
+
+        assert!(String::from("userId").validate_case(Case::Camel).is_ok());
+        assert!(String::from("Content-Type")
+            .validate_case(Case::Train)
+            .is_ok());
+
+        let violation = String::from("user Id")
+            .validate_case(Case::Camel)
+            .unwrap_err();
+
+        assert_eq!(CaseRule::Whitespace, violation.rule);
+        assert_eq!(4, violation.byte_offset);
+        assert_eq!(4, violation.char_offset);
+        assert_eq!("userId", violation.suggestion);
+        assert_eq!(
+            "not camelCase, whitespace is not allowed at character 4, did you mean `userId`?",
+            violation.to_string()
+        );
+
+        let violation = String::from("UserId")
+            .validate_case(Case::Camel)
+            .unwrap_err();
+        assert_eq!(
+            (CaseRule::LeadingUppercase, 0),
+            (violation.rule, violation.char_offset)
+        );
+
+        let violation = String::from("userid")
+            .validate_case(Case::Camel)
+            .unwrap_err();
+        assert_eq!(CaseRule::AllLowercase, violation.rule);
+
+        let violation = String::from("user#Id")
+            .validate_case(Case::Pascal)
+            .unwrap_err();
+        assert_eq!(CaseRule::LeadingLowercase, violation.rule);
+
+        let violation = String::from("größe_Name")
+            .validate_case(Case::Snake)
+            .unwrap_err();
+        assert_eq!(CaseRule::LeadingUppercase, violation.rule);
+        assert_eq!(8, violation.byte_offset);
+        assert_eq!(6, violation.char_offset);
+        assert_eq!("größe_name", violation.suggestion);
+
+        let violation = String::from("größe-name")
+            .validate_case(Case::Snake)
+            .unwrap_err();
+        assert_eq!(CaseRule::IllegalCharacter('-'), violation.rule);
+        assert_eq!(5, violation.char_offset);
+
+        let violation = String::from("max__retries")
+            .validate_case(Case::Snake)
+            .unwrap_err();
+        assert_eq!(
+            (CaseRule::EmptyWord, 4),
+            (violation.rule, violation.char_offset)
+        );
+
+        let violation = String::from("MAX_reTRIES")
+            .validate_case(Case::ScreamingSnake)
+            .unwrap_err();
+        assert_eq!(
+            (CaseRule::UnexpectedLowercase, 4),
+            (violation.rule, violation.char_offset)
+        );
+
+        let violation = String::from("Content-TYPE")
+            .validate_case(Case::Train)
+            .unwrap_err();
+        assert_eq!(
+            (CaseRule::UnexpectedUppercase, 9),
+            (violation.rule, violation.char_offset)
+        );
+
+        let violation = String::from("3d_model")
+            .validate_case(Case::Snake)
+            .unwrap_err();
+        assert_eq!(
+            (CaseRule::MisplacedDigit, 0),
+            (violation.rule, violation.char_offset)
+        );
+
+        let violation = String::new().validate_case(Case::Kebab).unwrap_err();
+        assert_eq!(CaseRule::Empty, violation.rule);
+    }
+
+    #[test]
+    fn traits_work_for_every_string_type() {
+        use crate::strings::methods::*;
+        use std::borrow::Cow;
+        use std::rc::Rc;
+        use std::sync::Arc;
+
+        assert_eq!("foo_bar", "foo bar".to_snake_case());
+        assert_eq!("foo_bar", Cow::Borrowed("fooBar").to_snake_case());
+        assert_eq!(
+            "foo_bar",
+            Cow::<str>::Owned("FooBar".to_string()).to_snake_case()
+        );
+        assert_eq!("foo_bar", Box::<str>::from("foo-bar").to_snake_case());
+        assert_eq!("foo_bar", Rc::<str>::from("foo.bar").to_snake_case());
+        assert_eq!("foo_bar", Arc::<str>::from("FOO_BAR").to_snake_case());
+
+        assert!("fooBar".is_camel_case());
+        assert!(Box::<str>::from("foo_bar").is_snake_case());
+        assert_eq!(Some(Case::Kebab), Rc::<str>::from("foo-bar").detect_case());
+        assert_eq!("foobar", Arc::<str>::from(" foo bar ").remove_whitespaces());
+        assert_eq!("f", Cow::Borrowed("foo").first());
+
+        let names: Vec<String> = ["aB", "cD"].iter().map(ToCases::to_snake_case).collect();
+        assert_eq!(vec!["a_b", "c_d"], names);
+    }
+
+    #[test]
+    fn to_rust_ident_works() {
+        use crate::strings::methods::ToIdentifier;
+
+        assert_eq!("r#type", "type".to_rust_ident());
+        assert_eq!("r#match", "Match".to_rust_ident());
+        assert_eq!("_3d_model", "3d_model".to_rust_ident());
+        assert_eq!("user_name", "user-name!".to_rust_ident());
+        assert_eq!("self_", "self".to_rust_ident());
+        assert_eq!("größe", "Größe".to_rust_ident());
+        assert_eq!("__", "$%&".to_rust_ident());
+        assert_eq!("__", "!!!".to_rust_ident());
+        assert_eq!("__", "".to_rust_ident());
+        assert_eq!("__", "_".to_rust_ident());
+    }
+
+    #[test]
+    fn to_python_ident_works() {
+        use crate::strings::methods::ToIdentifier;
+
+        assert_eq!("class_", "class".to_python_ident());
+        assert_eq!("user_id", "userId".to_python_ident());
+        assert_eq!("_1st_place", "1st place".to_python_ident());
+        assert_eq!("type", "type".to_python_ident());
+        assert_eq!("_", "$%&".to_python_ident());
+    }
+
+    #[test]
+    fn to_js_ident_works() {
+        use crate::strings::methods::ToIdentifier;
+
+        assert_eq!("new_", "new".to_js_ident());
+        assert_eq!("contentType", "content-type".to_js_ident());
+        assert_eq!("_2faEnabled", "2fa enabled".to_js_ident());
+        assert_eq!("match", "match".to_js_ident());
+    }
+
+    #[test]
+    fn to_identifier_with_case() {
+        use crate::strings::methods::{Case, Language, ToIdentifier};
+
+        assert_eq!(
+            "UserAccount",
+            "user_account".to_identifier_with(Language::Rust, Case::Pascal)
+        );
+        assert_eq!(
+            "Self_",
+            "self".to_identifier_with(Language::Rust, Case::Pascal)
+        );
+        assert_eq!(
+            "MAX_RETRIES",
+            "max-retries".to_identifier_with(Language::Python, Case::ScreamingSnake)
+        );
+        assert_eq!(
+            "user_id",
+            "userId".to_identifier_with(Language::JavaScript, Case::Kebab)
+        );
+        assert_eq!("r#type", "type".to_identifier(Language::Rust));
+    }
+
+    #[test]
+    fn pluralize_works() {
+        use crate::strings::methods::Inflect;
+
+        let cases = [
+            ("user", "users"),
+            ("category", "categories"),
+            ("day", "days"),
+            ("box", "boxes"),
+            ("church", "churches"),
+            ("class", "classes"),
+            ("status", "statuses"),
+            ("analysis", "analyses"),
+            ("knife", "knives"),
+            ("half", "halves"),
+            ("roof", "roofs"),
+            ("tomato", "tomatoes"),
+            ("person", "people"),
+            ("child", "children"),
+            ("Mouse", "Mice"),
+            ("sheep", "sheep"),
+            ("information", "information"),
+            ("users", "users"),
+            ("UserAccount", "UserAccounts"),
+            ("user account", "user accounts"),
+            ("blog_category", "blog_categories"),
+            ("USER", "USERS"),
+            ("CATEGORY", "CATEGORIES"),
+            ("PERSON", "PEOPLE"),
+            ("café", "cafés"),
+            ("größe", "größes"),
+            ("virus", "viruses"),
+            ("bus", "buses"),
+            ("bonus", "bonuses"),
+            ("campus", "campuses"),
+            ("gas", "gases"),
+            ("canvas", "canvases"),
+            ("basis", "bases"),
+            ("base", "bases"),
+            ("salesman", "salesmen"),
+            ("Chairman", "Chairmen"),
+            ("SALESMAN", "SALESMEN"),
+            ("grandchild", "grandchildren"),
+            ("salesperson", "salespeople"),
+            ("human", "humans"),
+            ("box", "boxes"),
+            ("areas", "areas"),
+            ("HTTPStatus", "HTTPStatuses"),
+            ("ID", "IDs"),
+            ("URL", "URLs"),
+            ("userID", "userIDs"),
+            ("", ""),
+        ];
+
+        for (singular, plural) in cases {
+            assert_eq!(plural, singular.pluralize(), "plural of {singular}");
+        }
+    }
+
+    #[test]
+    fn singularize_works() {
+        use crate::strings::methods::Inflect;
+
+        let cases = [
+            ("users", "user"),
+            ("categories", "category"),
+            ("days", "day"),
+            ("boxes", "box"),
+            ("churches", "church"),
+            ("classes", "class"),
+            ("class", "class"),
+            ("statuses", "status"),
+            ("status", "status"),
+            ("analyses", "analysis"),
+            ("knives", "knife"),
+            ("archives", "archive"),
+            ("halves", "half"),
+            ("movies", "movie"),
+            ("shoes", "shoe"),
+            ("people", "person"),
+            ("Children", "Child"),
+            ("sheep", "sheep"),
+            ("UserAccounts", "UserAccount"),
+            ("blog_categories", "blog_category"),
+            ("databases", "database"),
+            ("UserDatabases", "UserDatabase"),
+            ("abuses", "abuse"),
+            ("purchases", "purchase"),
+            ("bases", "base"),
+            ("bonuses", "bonus"),
+            ("campuses", "campus"),
+            ("gases", "gas"),
+            ("houses", "house"),
+            ("salesmen", "salesman"),
+            ("Chairmen", "Chairman"),
+            ("grandchildren", "grandchild"),
+            ("humans", "human"),
+            ("buses", "bus"),
+            ("viruses", "virus"),
+            ("HTTPStatuses", "HTTPStatus"),
+        ];
+
+        for (plural, singular) in cases {
+            assert_eq!(singular, plural.singularize(), "singular of {plural}");
+        }
+    }
+
+    #[test]
+    fn ordinalize_works() {
+        use crate::strings::methods::Inflect;
+
+        assert_eq!("1st", "1".ordinalize());
+        assert_eq!("2nd", "2".ordinalize());
+        assert_eq!("3rd", "3".ordinalize());
+        assert_eq!("4th", "4".ordinalize());
+        assert_eq!("11th", "11".ordinalize());
+        assert_eq!("12th", "12".ordinalize());
+        assert_eq!("13th", "13".ordinalize());
+        assert_eq!("22nd", "22".ordinalize());
+        assert_eq!("101st", "101".ordinalize());
+        assert_eq!("111th", "111".ordinalize());
+        assert_eq!("the 23rd", "the 23".ordinalize());
+        assert_eq!(
+            "123456789012345678901234567890th",
+            "123456789012345678901234567890".ordinalize()
+        );
+        assert_eq!("first", "first".ordinalize());
+    }
+
+    #[test]
+    fn tableize_works() {
+        use crate::strings::methods::Inflect;
+
+        assert_eq!("user_accounts", "UserAccount".tableize());
+        assert_eq!("blog_categories", "BlogCategory".tableize());
+        assert_eq!("people", "Person".tableize());
+        assert_eq!("http_statuses", "HTTPStatus".tableize());
+    }
+
+    #[test]
+    fn to_slug_works() {
+        use crate::strings::methods::ToSlug;
+
+        assert_eq!("creme-brulee", "Crème Brûlée".to_slug());
+        assert_eq!(
+            "creme-brulee",
+            "Cre\u{300}me Bru\u{302}le\u{301}e".to_slug()
+        );
+        assert_eq!("dont-panic-42", "  Don't   panic!! -- 42 ".to_slug());
+        assert_eq!("its-here", "It’s here".to_slug());
+        assert_eq!("strasse-uber-alles", "Straße_über.alles".to_slug());
+        assert_eq!("athina-ellada", "Αθήνα, Ελλάδα".to_slug());
+        assert_eq!("privet-mir", "Привет, мир".to_slug());
+        assert_eq!("shchuka-i-yozh", "Щука и ёж".to_slug());
+        assert_eq!("tokyo", "東京 Tokyo".to_slug());
+        assert_eq!("iphone-review", "iPhone Review".to_slug());
+        assert_eq!("", "!!!".to_slug());
+    }
+
+    #[test]
+    fn to_slug_with_max_length_works() {
+        use crate::strings::methods::ToSlug;
+
+        let case1 = "Crème Brûlée au chocolat";
+
+        assert_eq!(
+            "creme-brulee-au-chocolat",
+            case1.to_slug_with_max_length(100)
+        );
+        assert_eq!(
+            "creme-brulee-au-chocolat",
+            case1.to_slug_with_max_length(24)
+        );
+        assert_eq!("creme-brulee-au", case1.to_slug_with_max_length(23));
+        assert_eq!("creme-brulee-au", case1.to_slug_with_max_length(15));
+        assert_eq!("creme-brulee", case1.to_slug_with_max_length(14));
+        assert_eq!("creme-brulee", case1.to_slug_with_max_length(12));
+        assert_eq!("creme", case1.to_slug_with_max_length(11));
+        assert_eq!("cre", case1.to_slug_with_max_length(3));
+        assert_eq!("", case1.to_slug_with_max_length(0));
+    }
+
+    #[test]
+    fn display_width_works() {
+        use crate::strings::methods::DisplayWidth;
+
+        assert_eq!(0, "".display_width());
+        assert_eq!(5, "hello".display_width());
+        assert_eq!(6, "日本語".display_width());
+        assert_eq!(18, "ｆｕｌｌｗｉｄｔｈ".display_width());
+        assert_eq!(4, "e\u{301}lan".display_width());
+        assert_eq!(2, "👩‍💻".display_width());
+        assert_eq!(2, "🇯🇵".display_width());
+        assert_eq!(2, "❤\u{FE0F}".display_width());
+        assert_eq!(4, "한국".display_width());
+        assert_eq!(2, "a\u{200B}b".display_width());
+    }
+
+    #[test]
+    fn truncate_to_width_works() {
+        use crate::strings::methods::DisplayWidth;
+
+        assert_eq!("hello", "hello".truncate_to_width(5, "…"));
+        assert_eq!("hel…", "hello world".truncate_to_width(4, "…"));
+        assert_eq!("日本…", "日本語のテキスト".truncate_to_width(6, "…"));
+        assert_eq!("日本…", "日本語のテキスト".truncate_to_width(5, "…"));
+        assert_eq!("ab...", "abcdefgh".truncate_to_width(5, "..."));
+        assert_eq!("ab", "abcdefgh".truncate_to_width(2, "..."));
+        assert_eq!("", "日本".truncate_to_width(1, ""));
+        assert_eq!("e\u{301}…", "e\u{301}lan".truncate_to_width(2, "…"));
+    }
+
+    #[test]
+    fn pad_to_width_works() {
+        use crate::strings::methods::DisplayWidth;
+
+        assert_eq!("   42", "42".pad_left_to_width(5));
+        assert_eq!(" 山田", "山田".pad_left_to_width(5));
+        assert_eq!("name ", "name".pad_right_to_width(5));
+        assert_eq!("山田 ", "山田".pad_right_to_width(5));
+        assert_eq!("toolong", "toolong".pad_right_to_width(3));
+        assert_eq!("toolong", "toolong".pad_left_to_width(3));
+    }
+
+    #[test]
+    fn center_works() {
+        use crate::strings::methods::DisplayWidth;
+
+        assert_eq!("--abc---", "abc".center(8, '-'));
+        assert_eq!("*日本*", "日本".center(6, '*'));
+        assert_eq!(" 日本  ", "日本".center(7, ' '));
+        assert_eq!("中abc中 ", "abc".center(8, '中'));
+        assert_eq!("abc", "abc".center(2, '-'));
+    }
+
+    #[test]
+    fn wrap_works() {
+        use crate::strings::methods::Wrap;
+
+        assert_eq!(
+            vec!["the quick", "brown fox"],
+            "the quick brown fox".wrap(10)
+        );
+        assert_eq!(vec!["the quick brown fox"], "the quick brown fox".wrap(19));
+        assert_eq!(
+            vec!["the", "quick", "brown", "fox"],
+            "  the   quick\tbrown\nfox ".wrap(5)
+        );
+        assert_eq!(vec!["abcd", "efgh", "ij"], "abcdefghij".wrap(4));
+        assert_eq!(vec!["a", "abcd", "efgh", "i b"], "a abcdefghi b".wrap(4));
+        assert_eq!(
+            vec!["日本", "語の", "テキ", "スト"],
+            "日本語のテキスト".wrap(4)
+        );
+        assert_eq!(vec!["a", "b"], "a b".wrap(0));
+        assert!("".wrap(10).is_empty());
+        assert_eq!(vec![""], "   ".wrap(10));
+    }
+
+    #[test]
+    fn wrap_keeps_paragraphs() {
+        use crate::strings::methods::Wrap;
+
+        let case1 = "first paragraph\nstill first\n\nsecond one\r\n\n\nthird";
+
+        assert_eq!(
+            vec![
+                "first",
+                "paragraph",
+                "still first",
+                "",
+                "second one",
+                "",
+                "",
+                "third"
+            ],
+            case1.wrap(11)
+        );
+    }
+
+    #[test]
+    fn wrap_with_indents() {
+        use crate::strings::methods::{Wrap, WrapOptions};
+
+        let options = WrapOptions::new(12)
+            .initial_indent("- ")
+            .subsequent_indent("  ");
+
+        assert_eq!(
+            vec!["- first item", "  of the", "  list", "", "- second"],
+            "first item of the list\n\nsecond".wrap_with(&options)
+        );
+
+        let options = WrapOptions::new(6)
+            .initial_indent("> ")
+            .subsequent_indent("> ");
+
+        assert_eq!(
+            vec!["> abcd", "> efgh", "> ij"],
+            "abcdefghij".wrap_with(&options)
+        );
+    }
+
+    #[test]
+    fn fill_works() {
+        use crate::strings::methods::{Wrap, WrapOptions};
+
+        assert_eq!(
+            "the quick\nbrown fox\n\njumps",
+            "the quick brown fox\n\njumps".fill(10)
+        );
+        assert_eq!(
+            "    Usage:\n  run the\n  tool",
+            "Usage: run the tool".fill_with(
+                &WrapOptions::new(10)
+                    .initial_indent("    ")
+                    .subsequent_indent("  ")
+            )
+        );
+    }
+
+    #[test]
+    fn indent_works() {
+        use crate::strings::methods::Indent;
+
+        assert_eq!("    a: 1\n\n    b: 2\n", "a: 1\n\nb: 2\n".indent("    "));
+        assert_eq!("> a\r\n  \r\n> b", "a\r\n  \r\nb".indent("> "));
+        assert_eq!("", "".indent("  "));
+        assert_eq!("  single", "single".indent("  "));
+    }
+
+    #[test]
+    fn indent_with_works() {
+        use crate::strings::methods::Indent;
+
+        let numbered = "fn main() {\n\n}".indent_with(|line| format!("{} | ", line + 1));
+
+        assert_eq!("1 | fn main() {\n\n3 | }", numbered);
+
+        let mut calls = Vec::new();
+        let bulleted = "a\nb\n".indent_with(|line| {
+            calls.push(line);
+            if line == 0 {
+                "- "
+            } else {
+                "  "
+            }
+        });
+
+        assert_eq!("- a\n  b\n", bulleted);
+        assert_eq!(vec![0, 1], calls);
+    }
+
+    #[test]
+    fn dedent_works() {
+        use crate::strings::methods::Indent;
+
+        assert_eq!(
+            "a:\n  b: 1\n\nc: 2\n",
+            "    a:\n      b: 1\n  \n    c: 2\n".dedent()
+        );
+        assert_eq!("a\r\n b\r\n", "  a\r\n   b\r\n".dedent());
+        assert_eq!("\ta\n  b", "\ta\n  b".dedent());
+        assert_eq!("a\n\tb", "\ta\n\t\tb".dedent());
+        assert_eq!("no indent\n  here", "no indent\n  here".dedent());
+        assert_eq!("\n\n", "   \n \t\n".dedent());
+        assert_eq!("", "".dedent());
+    }
+
+    #[test]
+    fn edit_distances_work() {
+        use crate::strings::methods::Similarity;
+
+        assert_eq!(3, "kitten".levenshtein("sitting"));
+        assert_eq!(0, "same".levenshtein("same"));
+        assert_eq!(4, "".levenshtein("four"));
+        assert_eq!(2, "teh".levenshtein("the"));
+        assert_eq!(1, "teh".damerau_levenshtein("the"));
+        assert_eq!(3, "ca".damerau_levenshtein("abc"));
+        assert_eq!(1, "café".levenshtein("cafe"));
+        assert_eq!(1, String::from("colour").damerau_levenshtein("color"));
+    }
+
+    #[test]
+    fn similarity_scores_work() {
+        use crate::strings::methods::Similarity;
+
+        let close = |left: f64, right: f64| (left - right).abs() < 1e-3;
+
+        assert!(close(0.961, "martha".jaro_winkler("marhta")));
+        assert!(close(0.840, "dwayne".jaro_winkler("duane")));
+        assert!(close(0.813, "dixon".jaro_winkler("dicksonx")));
+        assert!(close(1.0, "".jaro_winkler("")));
+        assert!(close(0.0, "abc".jaro_winkler("")));
+        assert!(close(0.0, "abc".jaro_winkler("xyz")));
+
+        assert!(close(0.75, "node".normalized_similarity("mode")));
+        assert!(close(1.0, "".normalized_similarity("")));
+        assert!(close(0.0, "abc".normalized_similarity("")));
+    }
+
+    #[test]
+    fn closest_match_works() {
+        use crate::strings::methods::{Similarity, ToCases};
+
+        let keys = ["color", "columns", "border", "colors"];
+
+        assert_eq!(vec!["color", "colors"], "colour".closest_match(&keys, 0.6));
+        assert_eq!(vec!["color"], "colour".closest_match(&keys, 0.8));
+        assert!("width".closest_match(&keys, 0.7).is_empty());
+
+        let owned = vec![String::from("max_width"), String::from("min_width")];
+
+        assert_eq!(
+            vec!["max_width"],
+            "maxWidht".to_snake_case().closest_match(&owned, 0.8)
+        );
+
+        let many = ["ab", "ac", "ad", "ae"];
+
+        assert_eq!(vec!["ab", "ac", "ad"], "a".closest_match(&many, 0.0));
+    }
+
+    #[test]
+    fn natural_cmp_works() {
+        use crate::strings::methods::NaturalCmp;
+        use std::cmp::Ordering;
+
+        assert_eq!(Ordering::Less, "file2".natural_cmp("file10"));
+        assert_eq!(Ordering::Less, "v1.9".natural_cmp("v1.10"));
+        assert_eq!(Ordering::Greater, "v1.10.0".natural_cmp("v1.9.9"));
+        assert_eq!(Ordering::Equal, "abc".natural_cmp("abc"));
+        assert_eq!(Ordering::Less, "abc".natural_cmp("abcd"));
+        assert_eq!(Ordering::Less, "a1".natural_cmp("a01"));
+        assert_eq!(Ordering::Less, "a01".natural_cmp("a2"));
+        assert_eq!(Ordering::Less, "a01b".natural_cmp("a1c"));
+        assert_eq!(Ordering::Less, "x0".natural_cmp("x00"));
+        assert_eq!(
+            Ordering::Less,
+            "id99999999999999999999999".natural_cmp("id100000000000000000000000")
+        );
+        assert_eq!(Ordering::Less, "1abc".natural_cmp("abc"));
+        assert_eq!(Ordering::Less, "Zeta".natural_cmp("alpha"));
+
+        let mut files = vec![
+            "img12.png",
+            "img10.png",
+            "IMG2.png",
+            "img1.png",
+            "img02.png",
+        ];
+        files.sort_by(|a, b| a.natural_cmp(b));
+
+        assert_eq!(
+            vec![
+                "IMG2.png",
+                "img1.png",
+                "img02.png",
+                "img10.png",
+                "img12.png"
+            ],
+            files
+        );
+    }
+
+    #[test]
+    fn natural_cmp_ignoring_case_works() {
+        use crate::strings::methods::NaturalCmp;
+        use std::cmp::Ordering;
+
+        assert_eq!(Ordering::Less, "Zeta".natural_cmp_ignore_case("zeta2"));
+        assert_eq!(Ordering::Greater, "Zeta".natural_cmp_ignore_case("alpha"));
+        assert_eq!(Ordering::Equal, "ÉTÉ 3".natural_cmp_ignore_case("été 3"));
+        assert_eq!(
+            Ordering::Greater,
+            "Straße".natural_cmp_ignore_case("STRASSE")
+        );
+        assert_eq!(Ordering::Equal, "Straße".natural_cmp_folded("STRASSE"));
+        assert_eq!(Ordering::Equal, "ΣΟΦΟΣ".natural_cmp_folded("σοφος"));
+        assert_eq!(Ordering::Less, "Track 9".natural_cmp_folded("track 10"));
+
+        let mut tags = vec!["V1.10", "v1.9", "v1.9-RC1", "V1.2"];
+        tags.sort_by(|a, b| a.natural_cmp_ignore_case(b));
+
+        assert_eq!(vec!["V1.2", "v1.9", "v1.9-RC1", "V1.10"], tags);
+    }
+
+    #[test]
+    fn fuzzy_match_works() {
+        use crate::strings::methods::FuzzySearch;
+
+        let found = "src/strings/methods.rs".fuzzy_match("smr").unwrap();
+
+        assert_eq!(vec![4, 12, 20], found.positions);
+
+        let found = "openFile".fuzzy_match("of").unwrap();
+
+        assert_eq!(vec![0, 4], found.positions);
+        assert_eq!(None, "openFile".fuzzy_match("fo"));
+        assert_eq!(None, "ab".fuzzy_match("abc"));
+        assert_eq!(vec![1, 2], "ñandú".fuzzy_match("an").unwrap().positions);
+
+        let empty = "anything".fuzzy_match("").unwrap();
+
+        assert_eq!(0, empty.score);
+        assert!(empty.positions.is_empty());
+    }
+
+    #[test]
+    fn fuzzy_match_scores_work() {
+        use crate::strings::methods::FuzzySearch;
+
+        let score = |input: &str, pattern: &str| input.fuzzy_match(pattern).unwrap().score;
+
+        assert!(score("open_file", "of") > score("profile", "of"));
+        assert!(score("openFile", "of") > score("profile", "of"));
+        assert!(score("file", "fil") > score("f_i_l", "fil"));
+        assert!(score("git commit", "gc") > score("magic", "gc"));
+        assert!(score("src/user.rs", "user") > score("src/abuser.rs", "user"));
+        assert_eq!(
+            vec![4, 5, 6, 7],
+            "app/user/user_model.rs"
+                .fuzzy_match("user")
+                .unwrap()
+                .positions
+        );
+    }
+
+    #[test]
+    fn fuzzy_match_smart_case_works() {
+        use crate::strings::methods::FuzzySearch;
+
+        assert!("FooBar".fuzzy_match("fb").is_some());
+        assert!("FooBar".fuzzy_match("FB").is_some());
+        assert!("foobar".fuzzy_match("FB").is_none());
+        assert_eq!(vec![3, 4], "bar_Bar".fuzzy_match("_B").unwrap().positions);
+        assert_eq!(vec![0, 1], "İstanbul".fuzzy_match("is").unwrap().positions);
+        assert_eq!(
+            vec![3, 5],
+            "ΟΔΟΣ ΚΑΛΟΣ".fuzzy_match("σκ").unwrap().positions
+        );
+        assert_eq!(vec![3], "ΟΔΟΣ".fuzzy_match("ς").unwrap().positions);
+        assert_eq!(vec![4], "Straße".fuzzy_match("ß").unwrap().positions);
+        assert!("Straße".fuzzy_match("strs").is_none());
+        assert!("Strasse".fuzzy_match("ß").is_none());
+        assert!("ﬁle".fuzzy_match("f").is_none());
+    }
+
+    #[test]
+    fn fold_case_works() {
+        use crate::strings::methods::{Locale, LocaleCase};
+
+        assert_eq!("strasse", "Straße".fold_case());
+        assert_eq!("ss", "ẞ".fold_case());
+        assert_eq!("σοφοσ", "ΣΟΦΟΣ".fold_case());
+        assert_eq!("σοφοσ", "σοφος".fold_case());
+        assert_eq!("file", "ﬁle".fold_case());
+        assert_eq!("ı", "ı".fold_case());
+        assert_eq!("i\u{307}", "İ".fold_case());
+        assert_eq!("ıi", "Iİ".fold_case_in(Locale::Turkish));
+        assert_eq!("ıi", "Iİ".fold_case_in(Locale::Azerbaijani));
+        assert_eq!("ii\u{307}", "Iİ".fold_case_in(Locale::Lithuanian));
+    }
+
+    #[test]
+    fn eq_ignore_case_works() {
+        use crate::strings::methods::{Locale, LocaleCase};
+
+        assert!("MASSE".eq_ignore_case("Maße"));
+        assert!("ΣΊΣΥΦΟΣ".eq_ignore_case("σίσυφος"));
+        assert!("Hello".eq_ignore_case("hELLO"));
+        assert!(!"Hello".eq_ignore_case("Hell"));
+        assert!(String::from("KELVIN").eq_ignore_case("\u{212A}elvin"));
+        assert!("DİYARBAKIR".eq_ignore_case_in("diyarbakır", Locale::Turkish));
+        assert!(!"DİYARBAKIR".eq_ignore_case("diyarbakır"));
+        assert!(!"I".eq_ignore_case_in("i", Locale::Turkish));
+        assert!("I".eq_ignore_case("i"));
+    }
+
+    #[test]
+    fn locale_lowercase_and_uppercase_work() {
+        use crate::strings::methods::{Locale, LocaleCase};
+
+        assert_eq!("ısparta", "ISPARTA".to_lowercase_in(Locale::Turkish));
+        assert_eq!("isparta", "ISPARTA".to_lowercase_in(Locale::Root));
+        assert_eq!("istanbul", "İSTANBUL".to_lowercase_in(Locale::Azerbaijani));
+        assert_eq!("i", "I\u{307}".to_lowercase_in(Locale::Turkish));
+        assert_eq!("İSTANBUL", "istanbul".to_uppercase_in(Locale::Turkish));
+        assert_eq!("ISTANBUL", "istanbul".to_uppercase_in(Locale::Root));
+        assert_eq!("I", "ı".to_uppercase_in(Locale::Turkish));
+
+        assert_eq!("i\u{307}\u{300}", "Ì".to_lowercase_in(Locale::Lithuanian));
+        assert_eq!(
+            "i\u{307}\u{301}",
+            "I\u{301}".to_lowercase_in(Locale::Lithuanian)
+        );
+        assert_eq!("i", "I".to_lowercase_in(Locale::Lithuanian));
+        assert_eq!(
+            "I\u{301}",
+            "i\u{307}\u{301}".to_uppercase_in(Locale::Lithuanian)
+        );
+        assert_eq!("I\u{307}", "i\u{307}".to_uppercase_in(Locale::Root));
+
+        assert_eq!("όσος", "ΌΣΟΣ".to_lowercase_in(Locale::Root));
+        assert_eq!("σ", "Σ".to_lowercase_in(Locale::Turkish));
+    }
+
+    #[test]
+    fn cases_with_locale_work() {
+        use crate::strings::methods::*;
+
+        let turkish = CaseOptions::new().locale(Locale::Turkish);
+
+        assert_eq!(
+            "İstanbul",
+            "istanbul".convert_case_with(Case::Pascal, &turkish)
+        );
+        assert_eq!("Istanbul", "istanbul".to_pascal_case());
+        assert_eq!(
+            "ılık_su",
+            "ILIK SU".convert_case_with(Case::Snake, &turkish)
+        );
+        assert_eq!(
+            "KİŞİ_ADI",
+            "kişi adı".convert_case_with(Case::ScreamingSnake, &turkish)
+        );
+        assert_eq!(
+            "İzmir İli",
+            "izmir ili".convert_case_with(Case::Title, &turkish)
+        );
+        assert_eq!(
+            "kullanıcıAdı",
+            "KULLANICI ADI".convert_case_with(Case::Camel, &turkish)
+        );
+        assert_eq!(
+            "ıdea",
+            "IDEA".as_case_with(Case::Kebab, &turkish).to_string()
+        );
+    }
+
+    #[test]
+    fn locale_parses() {
+        use crate::strings::methods::Locale;
+
+        assert_eq!(Ok(Locale::Turkish), "tr".parse());
+        assert_eq!(Ok(Locale::Turkish), "tr-TR".parse());
+        assert_eq!(Ok(Locale::Azerbaijani), "az_Latn_AZ".parse());
+        assert_eq!(Ok(Locale::Lithuanian), "LT".parse());
+        assert_eq!(Ok(Locale::Root), "en-US".parse());
+        assert!("".parse::<Locale>().is_err());
+        assert!("english".parse::<Locale>().is_err());
+        assert_eq!(
+            "invalid language tag `t1`",
+            "t1".parse::<Locale>().unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn json_escaping_works() {
+        use crate::strings::methods::{Escape, UnescapeErrorKind};
+
+        let original = "say \"hi\" \\ path\n\t\u{1}\u{2028}é😀";
+        let escaped = original.escape_json();
+
+        assert_eq!(r#"say \"hi\" \\ path\n\t\u0001\u2028é😀"#, escaped);
+        assert_eq!(Ok(original.to_string()), escaped.unescape_json());
+        assert_eq!(Ok(String::from("/😀")), r"\/😀".unescape_json());
+
+        let error = r"ab\q".unescape_json().unwrap_err();
+
+        assert_eq!(UnescapeErrorKind::UnknownEscape('q'), error.kind);
+        assert_eq!(2, error.byte_offset);
+        assert_eq!(
+            UnescapeErrorKind::InvalidCodePoint(0xD83D),
+            r"é\ud83d!".unescape_json().unwrap_err().kind
+        );
+        assert_eq!(1, r"é\ud83d!".unescape_json().unwrap_err().char_offset);
+        assert_eq!(
+            UnescapeErrorKind::InvalidDigit('g'),
+            r"\u00g1".unescape_json().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::TruncatedEscape,
+            r"\u00".unescape_json().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::TruncatedEscape,
+            "abc\\".unescape_json().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::UnescapedCharacter('"'),
+            r#"a"b"#.unescape_json().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::UnescapedCharacter('\n'),
+            "a\nb".unescape_json().unwrap_err().kind
+        );
+    }
+
+    #[test]
+    fn c_escaping_works() {
+        use crate::strings::methods::{Escape, UnescapeErrorKind};
+
+        let original = "tab\t\"q\" \\ \u{7}\u{1b}1\u{7f} é";
+        let escaped = original.escape_c();
+
+        assert_eq!(r#"tab\t\"q\" \\ \a\0331\177 é"#, escaped);
+        assert_eq!(Ok(original.to_string()), escaped.unescape_c());
+        assert_eq!(Ok(String::from("café")), r"caf\xc3\xa9".unescape_c());
+        assert_eq!(Ok(String::from("A\0B")), r"\101\0\x42".unescape_c());
+        assert_eq!(Ok(String::from("é😀?")), r"é\U0001F600\?".unescape_c());
+
+        let error = r"ok\xc3!".unescape_c().unwrap_err();
+
+        assert_eq!(UnescapeErrorKind::InvalidUtf8, error.kind);
+        assert_eq!(2, error.byte_offset);
+        assert_eq!(
+            UnescapeErrorKind::InvalidCodePoint(0x141),
+            r"\x141".unescape_c().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::InvalidDigit('g'),
+            r"\xg".unescape_c().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::UnknownEscape('z'),
+            r"\z".unescape_c().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::InvalidCodePoint(0xD800),
+            r"\uD800".unescape_c().unwrap_err().kind
+        );
+    }
+
+    #[test]
+    fn markup_escaping_works() {
+        use crate::strings::methods::{Escape, UnescapeErrorKind};
+
+        let original = r#"<a href="x?a=1&b='2'">Tom & Jerry</a>"#;
+
+        assert_eq!(
+            "&lt;a href=&quot;x?a=1&amp;b=&#39;2&#39;&quot;&gt;Tom &amp; Jerry&lt;/a&gt;",
+            original.escape_html()
+        );
+        assert_eq!(
+            "&lt;a href=&quot;x?a=1&amp;b=&apos;2&apos;&quot;&gt;Tom &amp; Jerry&lt;/a&gt;",
+            original.escape_xml()
+        );
+        assert_eq!(
+            Ok(original.to_string()),
+            original.escape_html().unescape_html()
+        );
+        assert_eq!(
+            Ok(original.to_string()),
+            original.escape_xml().unescape_xml()
+        );
+
+        assert_eq!(
+            Ok(String::from("© R&D <b> 😀 —")),
+            "&copy; R&D <b> &#128512; &mdash;".unescape_html()
+        );
+        assert_eq!(Ok(String::from("a &b c")), "a &b c".unescape_html());
+
+        assert_eq!(
+            Ok(String::from("café Köln ñ Ω ∞ ‰")),
+            "caf&eacute; K&ouml;ln &ntilde; &Omega; &infin; &permil;".unescape_html()
+        );
+        assert_eq!(
+            Ok(String::from("x &bogus; y")),
+            "x &bogus; y".unescape_html()
+        );
+        assert_eq!(
+            Ok(String::from("AT&T; rocks")),
+            "AT&T; rocks".unescape_html()
+        );
+
+        let error = "x &bogus; y".unescape_xml().unwrap_err();
+
+        assert_eq!(
+            UnescapeErrorKind::UnknownEntity(String::from("bogus")),
+            error.kind
+        );
+        assert_eq!(2, error.byte_offset);
+        assert_eq!(
+            UnescapeErrorKind::UnterminatedEntity,
+            "&#65".unescape_html().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::InvalidCodePoint(0x0011_0000),
+            "&#x110000;".unescape_html().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::InvalidDigit('z'),
+            "&#12z;".unescape_xml().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::UnknownEntity(String::from("copy")),
+            "&copy;".unescape_xml().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::UnterminatedEntity,
+            "R&D".unescape_xml().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::UnescapedCharacter('<'),
+            "a < b".unescape_xml().unwrap_err().kind
+        );
+    }
+
+    #[test]
+    fn shell_quoting_works() {
+        use crate::strings::methods::{Escape, UnescapeErrorKind};
+
+        assert_eq!("report-2024.txt", "report-2024.txt".shell_quote());
+        assert_eq!("''", "".shell_quote());
+        assert_eq!("'hello world'", "hello world".shell_quote());
+        assert_eq!(r"'it'\''s'", "it's".shell_quote());
+        assert_eq!("'$HOME; rm -rf /'", "$HOME; rm -rf /".shell_quote());
+
+        for original in ["", "a b", "it's", "$(x) `y` \"z\" \\", "new\nline", "é"] {
+            assert_eq!(
+                Ok(original.to_string()),
+                original.shell_quote().unescape_shell()
+            );
+        }
+
+        assert_eq!(Ok(String::from("a b$c")), r#"a\ "b\$c""#.unescape_shell());
+        assert_eq!(Ok(String::from(r"\n")), r#""\n""#.unescape_shell());
+
+        assert_eq!(
+            UnescapeErrorKind::UnescapedCharacter('~'),
+            "~/x".unescape_shell().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::UnescapedCharacter('#'),
+            "#x".unescape_shell().unwrap_err().kind
+        );
+        assert_eq!(Ok(String::from("a#b~")), "a#b~".unescape_shell());
+        assert_eq!(Ok(String::from("~/x")), "'~/x'".unescape_shell());
+
+        let error = "'abc".unescape_shell().unwrap_err();
+
+        assert_eq!(UnescapeErrorKind::UnterminatedQuote, error.kind);
+        assert_eq!(0, error.byte_offset);
+        assert_eq!(
+            UnescapeErrorKind::UnescapedCharacter(' '),
+            "a b".unescape_shell().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::UnescapedCharacter('$'),
+            r#""$HOME""#.unescape_shell().unwrap_err().kind
+        );
+        assert_eq!(
+            UnescapeErrorKind::TruncatedEscape,
+            "abc\\".unescape_shell().unwrap_err().kind
+        );
+        assert_eq!(
+            "the quote is never closed at character 2",
+            r#"ab"cd"#.unescape_shell().unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn split_shell_words_works() {
+        use crate::strings::methods::SplitQuoted;
+
+        let words = r#"grep -e "hello world" 'it''s' a\ b"#.split_shell_words();
+
+        assert_eq!(
+            vec!["grep", "-e", "hello world", "its", "a b"],
+            words.unwrap()
+        );
+        assert_eq!(
+            vec!["--name=a b", "", "x"],
+            r#"  --name="a b" '' x  "#.split_shell_words().unwrap()
+        );
+        assert_eq!(
+            vec![r#"$HOME "q" \ \n"#],
+            r#""\$HOME \"q\" \\ \n""#.split_shell_words().unwrap()
+        );
+        assert_eq!(
+            vec!["one", "two"],
+            "one \\\n two".split_shell_words().unwrap()
+        );
+        assert_eq!(vec!["ab"], "a\\\nb".split_shell_words().unwrap());
+        assert_eq!(vec!["", "x"], "'' \\\nx".split_shell_words().unwrap());
+        assert_eq!(vec!["a|b;c"], "a|b;c".split_shell_words().unwrap());
+        assert_eq!(vec!["a"], "a # comment".split_shell_words().unwrap());
+        assert_eq!(
+            vec!["a#b", "#c", "d"],
+            "a#b '#c' # x y\nd".split_shell_words().unwrap()
+        );
+        assert!("# 'not closed".split_shell_words().unwrap().is_empty());
+        assert!("".split_shell_words().unwrap().is_empty());
+        assert!(" \t\n".split_shell_words().unwrap().is_empty());
+    }
+
+    #[test]
+    fn split_shell_words_errors_work() {
+        use crate::strings::methods::{SplitQuoted, UnescapeErrorKind};
+
+        let error = "echo 'oops".split_shell_words().unwrap_err();
+
+        assert_eq!(UnescapeErrorKind::UnterminatedQuote, error.kind);
+        assert_eq!(5, error.byte_offset);
+
+        let error = r#"é "a\""#.split_shell_words().unwrap_err();
+
+        assert_eq!(UnescapeErrorKind::UnterminatedQuote, error.kind);
+        assert_eq!(3, error.byte_offset);
+        assert_eq!(2, error.char_offset);
+        assert_eq!(
+            UnescapeErrorKind::TruncatedEscape,
+            "end\\".split_shell_words().unwrap_err().kind
+        );
+    }
+
+    #[test]
+    fn split_quoted_works() {
+        use crate::strings::methods::{SplitQuoted, UnescapeErrorKind};
+
+        assert_eq!(
+            vec!["name=Smith, J", "tag=a;b"],
+            r#"name="Smith, J";tag=a\;b"#.split_quoted(';', '"', '\\').unwrap()
+        );
+        assert_eq!(
+            vec!["1", r#"say "hi""#, "", "4"],
+            r#"1,"say ""hi""",,4"#.split_quoted(',', '"', '"').unwrap()
+        );
+        assert_eq!(
+            vec!["a:b", "c"],
+            "'a:b':c".split_quoted(':', '\'', '\\').unwrap()
+        );
+        assert_eq!(vec![""], "".split_quoted(',', '"', '\\').unwrap());
+        assert_eq!(vec!["", ""], ",".split_quoted(',', '"', '\\').unwrap());
+        assert_eq!(
+            vec!["ab,cd"],
+            r#"a"b,c"d"#.split_quoted(',', '"', '\\').unwrap()
+        );
+
+        let error = r#"a,"b,c"#.split_quoted(',', '"', '\\').unwrap_err();
+
+        assert_eq!(UnescapeErrorKind::UnterminatedQuote, error.kind);
+        assert_eq!(2, error.byte_offset);
+        assert_eq!(
+            UnescapeErrorKind::TruncatedEscape,
+            r"a,b\".split_quoted(',', '"', '\\').unwrap_err().kind
+        );
+    }
+
+    #[test]
+    fn join_shell_words_works() {
+        use crate::strings::methods::{JoinShellWords, SplitQuoted};
+
+        let words = vec!["echo", "it's", "a b", "", "$HOME", "x\ny"];
+        let line = words.join_shell_words();
+
+        assert_eq!("echo 'it'\\''s' 'a b' '' '$HOME' 'x\ny'", line);
+        assert_eq!(words, line.split_shell_words().unwrap());
+
+        let owned = [String::from("ls"), String::from("-la")];
+
+        assert_eq!("ls -la", owned.join_shell_words());
+        assert_eq!("", Vec::<String>::new().join_shell_words());
+    }
+
+    #[test]
+    fn format_named_works() {
+        use crate::strings::methods::FormatNamed;
+        use std::collections::{BTreeMap, HashMap};
+
+        let values = HashMap::from([("user", "Ada"), ("count", "3")]);
+
+        assert_eq!(
+            "Hi Ada, you have 3 new messages",
+            "Hi {user}, you have {count} new {kind:-messages}".format_named(&values)
+        );
+        assert_eq!(
+            "{user} is Ada, {} and {x",
+            "{{user}} is {user}, {} and {x".format_named(&values)
+        );
+        assert_eq!("a } b", "a } b".format_named(&values));
+        assert_eq!("Ada", "{user:-nobody}".format_named(&values));
+        assert_eq!("", "{title:-}".format_named(&values));
+        assert_eq!("Hi {name}!", "Hi {name}!".format_named(&values));
+        assert_eq!("{Ada}", "{{{user}}}".format_named(&values));
+
+        let numbers: HashMap<String, u32> = HashMap::from([(String::from("n"), 42)]);
+
+        assert_eq!("n = 42", "n = {n}".format_named(&numbers));
+
+        let sorted = BTreeMap::from([("pi", 3.5)]);
+
+        assert_eq!("about 3.5", "about {pi}".format_named(&sorted));
+
+        let closure = |name: &str| name.strip_prefix("env.").map(str::to_uppercase);
+
+        assert_eq!(
+            "HOME and {other}",
+            "{env.home} and {other}".format_named(&closure)
+        );
+    }
+
+    #[test]
+    fn format_named_strict_works() {
+        use crate::strings::methods::{FormatNamed, MissingKey};
+        use std::collections::HashMap;
+
+        let values = HashMap::from([("user", "Ada")]);
+
+        assert_eq!(
+            Ok(String::from("Hi Ada, welcome")),
+            "Hi {user}, {greeting:-welcome}".format_named_strict(&values)
+        );
+
+        let error = "é {user} {first} and {second}"
+            .format_named_strict(&values)
+            .unwrap_err();
+
+        assert_eq!(
+            vec![
+                MissingKey {
+                    name: String::from("first"),
+                    byte_offset: 10,
+                    char_offset: 9,
+                },
+                MissingKey {
+                    name: String::from("second"),
+                    byte_offset: 22,
+                    char_offset: 21,
+                },
+            ],
+            error.missing
+        );
+        assert_eq!(
+            "missing values for `first` at character 9, `second` at character 21",
+            error.to_string()
+        );
+    }
+
+    #[test]
+    fn matches_glob_works() {
+        use crate::strings::methods::MatchesGlob;
+
+        assert!("main.rs".matches_glob("*.rs"));
+        assert!(!"src/main.rs".matches_glob("*.rs"));
+        assert!("src/main.rs".matches_glob("src/*.rs"));
+        assert!("a.b".matches_glob("a?b"));
+        assert!(!"a/b".matches_glob("a?b"));
+        assert!("".matches_glob("*"));
+        assert!("".matches_glob(""));
+        assert!(!"x".matches_glob(""));
+        assert!("abc".matches_glob("a*b*c"));
+        assert!("axxbyyc".matches_glob("a*b*c"));
+        assert!(!"axxbyy".matches_glob("a*b*c"));
+        assert!("a*b".matches_glob(r"a\*b"));
+        assert!(!"axb".matches_glob(r"a\*b"));
+        assert!(!"abc".matches_glob("[abc"));
+    }
+
+    #[test]
+    fn glob_classes_work() {
+        use crate::strings::methods::MatchesGlob;
+
+        assert!("file7.txt".matches_glob("file[0-9].txt"));
+        assert!(!"fileA.txt".matches_glob("file[0-9].txt"));
+        assert!("fileA.txt".matches_glob("file[!0-9].txt"));
+        assert!("fileA.txt".matches_glob("file[^0-9].txt"));
+        assert!("b".matches_glob("[abc]"));
+        assert!(!"d".matches_glob("[abc]"));
+        assert!("]".matches_glob("[]a]"));
+        assert!("-".matches_glob("[a-]"));
+        assert!("-".matches_glob(r"[a\-z]"));
+        assert!(!"m".matches_glob(r"[a\-z]"));
+        assert!("é".matches_glob("[à-ÿ]"));
+        assert!(!"a/b".matches_glob("a[!x]b"));
+    }
+
+    #[test]
+    fn glob_double_stars_work() {
+        use crate::strings::methods::MatchesGlob;
+
+        let pattern = "project/*/secrets/**";
+
+        assert!("project/api/secrets/db/password".matches_glob(pattern));
+        assert!("project/api/secrets/key".matches_glob(pattern));
+        assert!(!"project/api/secrets".matches_glob(pattern));
+        assert!(!"project/a/b/secrets/key".matches_glob(pattern));
+
+        assert!("a/b".matches_glob("a/**/b"));
+        assert!("a/x/b".matches_glob("a/**/b"));
+        assert!("a/x/y/b".matches_glob("a/**/b"));
+        assert!(!"a/xb".matches_glob("a/**/b"));
+        assert!("b.rs".matches_glob("**/*.rs"));
+        assert!("src/strings/methods.rs".matches_glob("**/*.rs"));
+        assert!("src/strings/methods.rs".matches_glob("src/**/*.rs"));
+        assert!("anything/at/all".matches_glob("**"));
+        assert!("abxc".matches_glob("a**c"));
+        assert!(!"ab/c".matches_glob("a**c"));
+    }
+
+    #[test]
+    fn glob_pattern_works() {
+        use crate::strings::methods::*;
+
+        let secrets: GlobPattern = "project/*/secrets/**".parse().unwrap();
+
+        assert!(secrets.is_match("project/api/secrets/db"));
+        assert!(!secrets.is_match("project/api/public/db"));
+        assert!(!secrets.is_match("PROJECT/api/secrets/db"));
+        assert_eq!("project/*/secrets/**", secrets.as_str());
+        assert_eq!("project/*/secrets/**", secrets.to_string());
+
+        let insensitive = secrets.case_insensitive(true);
+
+        assert!(insensitive.is_match("PROJECT/Api/Secrets/DB"));
+        assert!(!insensitive
+            .case_insensitive(false)
+            .is_match("PROJECT/api/secrets/db"));
+
+        let images = GlobPattern::new("*.[jp][pn]g")
+            .unwrap()
+            .case_insensitive(true);
+
+        assert!(images.is_match("Photo.PNG"));
+        assert!(images.is_match("scan.jpg"));
+        assert!("STRASSE.txt".matches_glob_ignore_case("straße.*"));
+        assert!("Q".matches_glob_ignore_case("[a-z]"));
+        assert!(!"Q".matches_glob("[a-z]"));
+        assert!("q".matches_glob_ignore_case("[A-Z]"));
+    }
+
+    #[test]
+    fn glob_ignore_case_keeps_whole_characters() {
+        use crate::strings::methods::MatchesGlob;
+
+        assert!("ß".matches_glob_ignore_case("?"));
+        assert!("ß".matches_glob_ignore_case("[ß]"));
+        assert!("ß".matches_glob_ignore_case("[ẞ]"));
+        assert!(!"ß".matches_glob_ignore_case("??"));
+        assert!("Maße.txt".matches_glob_ignore_case("ma?e.*"));
+        assert!("İ".matches_glob_ignore_case("?"));
+        assert!("İ".matches_glob_ignore_case("[İ]"));
+        assert!("İZMİR".matches_glob_ignore_case("?zm?r"));
+        assert!("İZMİR".matches_glob_ignore_case("*r"));
+        assert!(!"İ".matches_glob_ignore_case("[!İ]"));
+
+        assert!("_".matches_glob("[A-z]"));
+        assert!("_".matches_glob_ignore_case("[A-z]"));
+        assert!("`".matches_glob_ignore_case("[A-z]"));
+        assert!("_".matches_glob_ignore_case("[Z-a]"));
+        assert!(!"b".matches_glob("[Z-a]"));
+    }
+
+    #[test]
+    fn glob_errors_work() {
+        use crate::strings::methods::{GlobErrorKind, GlobPattern};
+
+        let error = GlobPattern::new("é/[abc").unwrap_err();
+
+        assert_eq!(GlobErrorKind::UnclosedClass, error.kind);
+        assert_eq!(3, error.byte_offset);
+        assert_eq!(2, error.char_offset);
+        assert_eq!(
+            GlobErrorKind::TrailingEscape,
+            GlobPattern::new("abc\\").unwrap_err().kind
+        );
+        assert_eq!(
+            GlobErrorKind::UnclosedClass,
+            GlobPattern::new(r"[a\]").unwrap_err().kind
+        );
+        assert_eq!(
+            "invalid glob pattern, the `[` is never closed at character 0",
+            GlobPattern::new("[").unwrap_err().to_string()
+        );
+    }
+
     #[test]
     fn first() {
         use crate::strings::methods::SelectNth;
@@ -120,4 +1982,80 @@ mod tests {
 
         assert_eq!("Helo world".first(), "H");
     }
+
+    #[test]
+    fn first_keeps_graphemes_together() {
+        use crate::strings::methods::SelectNth;
+
+        assert_eq!("", "".first());
+        assert_eq!("e\u{301}", "e\u{301}lan".first());
+        assert_eq!("👩‍💻", "👩‍💻 dev".first());
+        assert_eq!("\r\n", "\r\nnext".first());
+    }
+
+    #[test]
+    fn nth_and_last() {
+        use crate::strings::methods::SelectNth;
+
+        let case1 = "hola 🇪🇸🇩🇪";
+
+        assert_eq!(Some("h"), case1.nth(0));
+        assert_eq!(Some("🇪🇸"), case1.nth(5));
+        assert_eq!(Some("🇩🇪"), case1.nth(6));
+        assert_eq!(None, case1.nth(7));
+        assert_eq!(Some("🇩🇪"), case1.last());
+        assert_eq!(Some("👍🏽"), "nice 👍🏽".last());
+        assert_eq!(Some("a"), String::from("a").last());
+        assert_eq!(None, "".last());
+    }
+
+    #[test]
+    fn nth_keeps_hangul_syllables_together() {
+        use crate::strings::methods::SelectNth;
+
+        let case1 = "\u{1100}\u{1161}\u{11A8}\u{1100}\u{1161}";
+
+        assert_eq!(Some("\u{1100}\u{1161}\u{11A8}"), case1.nth(0));
+        assert_eq!(Some("\u{1100}\u{1161}"), case1.nth(1));
+        assert_eq!(None, case1.nth(2));
+        assert_eq!(Some("\u{AC00}\u{11A8}"), "\u{AC00}\u{11A8}\u{AC01}".nth(0));
+        assert_eq!(Some("\u{AC01}"), "\u{AC00}\u{11A8}\u{AC01}".nth(1));
+        assert_eq!(Some("\u{AC01}\u{11A8}"), "\u{AC01}\u{11A8}\u{1161}".nth(0));
+        assert_eq!(Some("\u{1161}"), "\u{AC01}\u{11A8}\u{1161}".nth(1));
+        assert_eq!(Some("한"), "한국어".nth(0));
+    }
+
+    #[test]
+    fn first_n_and_last_n() {
+        use crate::strings::methods::SelectNth;
+
+        let case1 = "Zoë 👩‍👩‍👧";
+
+        assert_eq!("Zo", case1.first_n(2));
+        assert_eq!("Zoë 👩‍👩‍👧", case1.first_n(50));
+        assert_eq!("", case1.first_n(0));
+        assert_eq!("ë 👩‍👩‍👧", case1.last_n(3));
+        assert_eq!("Zoë 👩‍👩‍👧", case1.last_n(50));
+        assert_eq!("", case1.last_n(0));
+    }
+
+    #[test]
+    fn char_slice_never_panics() {
+        use crate::strings::methods::SelectNth;
+
+        let case1 = "Zoë 👩‍👩‍👧 Ünal";
+
+        assert_eq!(Some("Zoë"), case1.char_slice(0..3));
+        assert_eq!(Some("👩‍👩‍👧"), case1.char_slice(4..5));
+        assert_eq!(Some("👩‍👩‍👧 Ü"), case1.char_slice(4..=6));
+        assert_eq!(Some("Ünal"), case1.char_slice(6..));
+        assert_eq!(Some(case1), case1.char_slice(..));
+        assert_eq!(Some(""), case1.char_slice(10..10));
+        assert_eq!(None, case1.char_slice(6..20));
+        assert_eq!(None, case1.char_slice(11..));
+        assert_eq!(None, case1.char_slice(..=usize::MAX));
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = case1.char_slice(5..2);
+        assert_eq!(None, reversed);
+    }
 }
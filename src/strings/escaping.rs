@@ -0,0 +1,662 @@
+//! Escaping and unescaping of strings embedded in JSON, C, HTML, XML and POSIX shell sources
+
+use std::fmt::Write;
+
+use super::methods::UnescapeErrorKind;
+
+/// The result of the unescaping functions, the error holds the byte offset where the problem is
+type Unescaped = Result<String, (UnescapeErrorKind, usize)>;
+
+/// The named character references of HTML 4 understood by [`unescape_html`] besides the ones of
+/// XML, sorted by character
+const HTML_ENTITIES: &[(&str, char)] = &[
+    ("nbsp", '\u{A0}'),
+    ("iexcl", '¡'),
+    ("cent", '¢'),
+    ("pound", '£'),
+    ("curren", '¤'),
+    ("yen", '¥'),
+    ("brvbar", '¦'),
+    ("sect", '§'),
+    ("uml", '¨'),
+    ("copy", '©'),
+    ("ordf", 'ª'),
+    ("laquo", '«'),
+    ("not", '¬'),
+    ("shy", '\u{AD}'),
+    ("reg", '®'),
+    ("macr", '¯'),
+    ("deg", '°'),
+    ("plusmn", '±'),
+    ("sup2", '²'),
+    ("sup3", '³'),
+    ("acute", '´'),
+    ("micro", 'µ'),
+    ("para", '¶'),
+    ("middot", '·'),
+    ("cedil", '¸'),
+    ("sup1", '¹'),
+    ("ordm", 'º'),
+    ("raquo", '»'),
+    ("frac14", '¼'),
+    ("frac12", '½'),
+    ("frac34", '¾'),
+    ("iquest", '¿'),
+    ("Agrave", 'À'),
+    ("Aacute", 'Á'),
+    ("Acirc", 'Â'),
+    ("Atilde", 'Ã'),
+    ("Auml", 'Ä'),
+    ("Aring", 'Å'),
+    ("AElig", 'Æ'),
+    ("Ccedil", 'Ç'),
+    ("Egrave", 'È'),
+    ("Eacute", 'É'),
+    ("Ecirc", 'Ê'),
+    ("Euml", 'Ë'),
+    ("Igrave", 'Ì'),
+    ("Iacute", 'Í'),
+    ("Icirc", 'Î'),
+    ("Iuml", 'Ï'),
+    ("ETH", 'Ð'),
+    ("Ntilde", 'Ñ'),
+    ("Ograve", 'Ò'),
+    ("Oacute", 'Ó'),
+    ("Ocirc", 'Ô'),
+    ("Otilde", 'Õ'),
+    ("Ouml", 'Ö'),
+    ("times", '×'),
+    ("Oslash", 'Ø'),
+    ("Ugrave", 'Ù'),
+    ("Uacute", 'Ú'),
+    ("Ucirc", 'Û'),
+    ("Uuml", 'Ü'),
+    ("Yacute", 'Ý'),
+    ("THORN", 'Þ'),
+    ("szlig", 'ß'),
+    ("agrave", 'à'),
+    ("aacute", 'á'),
+    ("acirc", 'â'),
+    ("atilde", 'ã'),
+    ("auml", 'ä'),
+    ("aring", 'å'),
+    ("aelig", 'æ'),
+    ("ccedil", 'ç'),
+    ("egrave", 'è'),
+    ("eacute", 'é'),
+    ("ecirc", 'ê'),
+    ("euml", 'ë'),
+    ("igrave", 'ì'),
+    ("iacute", 'í'),
+    ("icirc", 'î'),
+    ("iuml", 'ï'),
+    ("eth", 'ð'),
+    ("ntilde", 'ñ'),
+    ("ograve", 'ò'),
+    ("oacute", 'ó'),
+    ("ocirc", 'ô'),
+    ("otilde", 'õ'),
+    ("ouml", 'ö'),
+    ("divide", '÷'),
+    ("oslash", 'ø'),
+    ("ugrave", 'ù'),
+    ("uacute", 'ú'),
+    ("ucirc", 'û'),
+    ("uuml", 'ü'),
+    ("yacute", 'ý'),
+    ("thorn", 'þ'),
+    ("yuml", 'ÿ'),
+    ("OElig", 'Œ'),
+    ("oelig", 'œ'),
+    ("Scaron", 'Š'),
+    ("scaron", 'š'),
+    ("Yuml", 'Ÿ'),
+    ("fnof", 'ƒ'),
+    ("circ", 'ˆ'),
+    ("tilde", '˜'),
+    ("Alpha", 'Α'),
+    ("Beta", 'Β'),
+    ("Gamma", 'Γ'),
+    ("Delta", 'Δ'),
+    ("Epsilon", 'Ε'),
+    ("Zeta", 'Ζ'),
+    ("Eta", 'Η'),
+    ("Theta", 'Θ'),
+    ("Iota", 'Ι'),
+    ("Kappa", 'Κ'),
+    ("Lambda", 'Λ'),
+    ("Mu", 'Μ'),
+    ("Nu", 'Ν'),
+    ("Xi", 'Ξ'),
+    ("Omicron", 'Ο'),
+    ("Pi", 'Π'),
+    ("Rho", 'Ρ'),
+    ("Sigma", 'Σ'),
+    ("Tau", 'Τ'),
+    ("Upsilon", 'Υ'),
+    ("Phi", 'Φ'),
+    ("Chi", 'Χ'),
+    ("Psi", 'Ψ'),
+    ("Omega", 'Ω'),
+    ("alpha", 'α'),
+    ("beta", 'β'),
+    ("gamma", 'γ'),
+    ("delta", 'δ'),
+    ("epsilon", 'ε'),
+    ("zeta", 'ζ'),
+    ("eta", 'η'),
+    ("theta", 'θ'),
+    ("iota", 'ι'),
+    ("kappa", 'κ'),
+    ("lambda", 'λ'),
+    ("mu", 'μ'),
+    ("nu", 'ν'),
+    ("xi", 'ξ'),
+    ("omicron", 'ο'),
+    ("pi", 'π'),
+    ("rho", 'ρ'),
+    ("sigmaf", 'ς'),
+    ("sigma", 'σ'),
+    ("tau", 'τ'),
+    ("upsilon", 'υ'),
+    ("phi", 'φ'),
+    ("chi", 'χ'),
+    ("psi", 'ψ'),
+    ("omega", 'ω'),
+    ("thetasym", 'ϑ'),
+    ("upsih", 'ϒ'),
+    ("piv", 'ϖ'),
+    ("ensp", '\u{2002}'),
+    ("emsp", '\u{2003}'),
+    ("thinsp", '\u{2009}'),
+    ("zwnj", '\u{200C}'),
+    ("zwj", '\u{200D}'),
+    ("lrm", '\u{200E}'),
+    ("rlm", '\u{200F}'),
+    ("ndash", '–'),
+    ("mdash", '—'),
+    ("lsquo", '‘'),
+    ("rsquo", '’'),
+    ("sbquo", '‚'),
+    ("ldquo", '“'),
+    ("rdquo", '”'),
+    ("bdquo", '„'),
+    ("dagger", '†'),
+    ("Dagger", '‡'),
+    ("bull", '•'),
+    ("hellip", '…'),
+    ("permil", '‰'),
+    ("prime", '′'),
+    ("Prime", '″'),
+    ("lsaquo", '‹'),
+    ("rsaquo", '›'),
+    ("oline", '‾'),
+    ("frasl", '⁄'),
+    ("euro", '€'),
+    ("image", 'ℑ'),
+    ("weierp", '℘'),
+    ("real", 'ℜ'),
+    ("trade", '™'),
+    ("alefsym", 'ℵ'),
+    ("larr", '←'),
+    ("uarr", '↑'),
+    ("rarr", '→'),
+    ("darr", '↓'),
+    ("harr", '↔'),
+    ("crarr", '↵'),
+    ("lArr", '⇐'),
+    ("uArr", '⇑'),
+    ("rArr", '⇒'),
+    ("dArr", '⇓'),
+    ("hArr", '⇔'),
+    ("forall", '∀'),
+    ("part", '∂'),
+    ("exist", '∃'),
+    ("empty", '∅'),
+    ("nabla", '∇'),
+    ("isin", '∈'),
+    ("notin", '∉'),
+    ("ni", '∋'),
+    ("prod", '∏'),
+    ("sum", '∑'),
+    ("minus", '−'),
+    ("lowast", '∗'),
+    ("radic", '√'),
+    ("prop", '∝'),
+    ("infin", '∞'),
+    ("ang", '∠'),
+    ("and", '∧'),
+    ("or", '∨'),
+    ("cap", '∩'),
+    ("cup", '∪'),
+    ("int", '∫'),
+    ("there4", '∴'),
+    ("sim", '∼'),
+    ("cong", '≅'),
+    ("asymp", '≈'),
+    ("ne", '≠'),
+    ("equiv", '≡'),
+    ("le", '≤'),
+    ("ge", '≥'),
+    ("sub", '⊂'),
+    ("sup", '⊃'),
+    ("nsub", '⊄'),
+    ("sube", '⊆'),
+    ("supe", '⊇'),
+    ("oplus", '⊕'),
+    ("otimes", '⊗'),
+    ("perp", '⊥'),
+    ("sdot", '⋅'),
+    ("lceil", '⌈'),
+    ("rceil", '⌉'),
+    ("lfloor", '⌊'),
+    ("rfloor", '⌋'),
+    ("lang", '\u{2329}'),
+    ("rang", '\u{232A}'),
+    ("loz", '◊'),
+    ("spades", '♠'),
+    ("clubs", '♣'),
+    ("hearts", '♥'),
+    ("diams", '♦'),
+];
+
+/// The predefined entities of XML, also understood by [`unescape_html`]
+const XML_ENTITIES: &[(&str, char)] = &[
+    ("amp", '&'),
+    ("lt", '<'),
+    ("gt", '>'),
+    ("quot", '"'),
+    ("apos", '\''),
+];
+
+/// Escapes `input` to be the content of a JSON string, the quotes around it are not added
+///
+/// `"`, `\` and the control characters are escaped, and so are the line and paragraph
+/// separators `U+2028` and `U+2029` that older JavaScript engines do not accept in strings
+pub(crate) fn escape_json(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for character in input.chars() {
+        match character {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            '\u{8}' => output.push_str("\\b"),
+            '\u{C}' => output.push_str("\\f"),
+            '\0'..='\u{1F}' | '\u{2028}' | '\u{2029}' => {
+                let _ = write!(output, "\\u{:04x}", u32::from(character));
+            }
+            _ => output.push(character),
+        }
+    }
+    output
+}
+
+/// Reads the content of a JSON string, without the quotes around it
+///
+/// # Errors
+///
+/// Unknown escapes, unescaped `"` and control characters, and `\u` escapes that are not valid
+/// hexadecimal or that leave a surrogate unpaired are rejected
+pub(crate) fn unescape_json(input: &str) -> Unescaped {
+    let mut output = String::with_capacity(input.len());
+    let mut characters = input.char_indices();
+    while let Some((offset, character)) = characters.next() {
+        match character {
+            '\\' => {}
+            '"' | '\0'..='\u{1F}' => {
+                return Err((UnescapeErrorKind::UnescapedCharacter(character), offset));
+            }
+            _ => {
+                output.push(character);
+                continue;
+            }
+        }
+
+        let (_, escaped) = characters
+            .next()
+            .ok_or((UnescapeErrorKind::TruncatedEscape, offset))?;
+        let unescaped = match escaped {
+            '"' | '\\' | '/' => escaped,
+            'b' => '\u{8}',
+            'f' => '\u{C}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'u' => {
+                let high = read_hex(&mut characters, offset, 4)?;
+                let code = if (0xD800..0xDC00).contains(&high) {
+                    let low = characters
+                        .as_str()
+                        .strip_prefix("\\u")
+                        .and_then(|digits| digits.get(..4))
+                        .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+                        .filter(|low| (0xDC00..0xE000).contains(low))
+                        .ok_or((UnescapeErrorKind::InvalidCodePoint(high), offset))?;
+                    characters.nth(5);
+                    0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00)
+                } else {
+                    high
+                };
+                char::from_u32(code).ok_or((UnescapeErrorKind::InvalidCodePoint(code), offset))?
+            }
+            other => return Err((UnescapeErrorKind::UnknownEscape(other), offset)),
+        };
+        output.push(unescaped);
+    }
+    Ok(output)
+}
+
+/// Escapes `input` to be the content of a C string literal, the quotes around it are not added
+///
+/// `"`, `\` and the control characters are escaped, the control characters without a short
+/// escape become 3-digit octal escapes, which unlike `\x` never swallow the next characters
+pub(crate) fn escape_c(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for character in input.chars() {
+        match character {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            '\u{7}' => output.push_str("\\a"),
+            '\u{8}' => output.push_str("\\b"),
+            '\u{B}' => output.push_str("\\v"),
+            '\u{C}' => output.push_str("\\f"),
+            '\0'..='\u{1F}' | '\u{7F}' => {
+                let _ = write!(output, "\\{:03o}", u32::from(character));
+            }
+            _ => output.push(character),
+        }
+    }
+    output
+}
+
+/// Reads the content of a C string literal, without the quotes around it
+///
+/// Octal and `\x` escapes are bytes that together must form valid UTF-8, `\u` and `\U`
+/// escapes are code points
+///
+/// # Errors
+///
+/// Unknown escapes, escapes without digits, bytes above `0xFF`, invalid code points and
+/// bytes that do not form valid UTF-8 are rejected
+pub(crate) fn unescape_c(input: &str) -> Unescaped {
+    let mut output = Vec::with_capacity(input.len());
+    // Where every escaped byte is in `output` and in `input`, to find where invalid UTF-8 is
+    let mut escaped_bytes = Vec::new();
+    let mut characters = input.char_indices().peekable();
+    while let Some((offset, character)) = characters.next() {
+        if character != '\\' {
+            push_char(&mut output, character);
+            continue;
+        }
+
+        let (_, escaped) = characters
+            .next()
+            .ok_or((UnescapeErrorKind::TruncatedEscape, offset))?;
+        let unescaped = match escaped {
+            'a' => '\u{7}',
+            'b' => '\u{8}',
+            'f' => '\u{C}',
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            'v' => '\u{B}',
+            '\\' | '\'' | '"' | '?' => escaped,
+            '0'..='7' | 'x' => {
+                let (radix, max_digits, mut value) = match escaped.to_digit(8) {
+                    Some(digit) => (8, 3, Some(digit)),
+                    None => (16, usize::MAX, None),
+                };
+                let mut count = usize::from(value.is_some());
+                while count < max_digits {
+                    let Some(digit) = characters.peek().and_then(|(_, c)| c.to_digit(radix)) else {
+                        break;
+                    };
+                    characters.next();
+                    count += 1;
+                    value = Some(
+                        value
+                            .unwrap_or(0)
+                            .saturating_mul(radix)
+                            .saturating_add(digit),
+                    );
+                }
+                let value = value.ok_or(match characters.peek() {
+                    Some(&(_, next)) => (UnescapeErrorKind::InvalidDigit(next), offset),
+                    None => (UnescapeErrorKind::TruncatedEscape, offset),
+                })?;
+                let byte = u8::try_from(value)
+                    .map_err(|_| (UnescapeErrorKind::InvalidCodePoint(value), offset))?;
+                escaped_bytes.push((output.len(), offset));
+                output.push(byte);
+                continue;
+            }
+            'u' | 'U' => {
+                let digits = if escaped == 'u' { 4 } else { 8 };
+                let code = read_hex(&mut characters, offset, digits)?;
+                char::from_u32(code).ok_or((UnescapeErrorKind::InvalidCodePoint(code), offset))?
+            }
+            other => return Err((UnescapeErrorKind::UnknownEscape(other), offset)),
+        };
+        push_char(&mut output, unescaped);
+    }
+
+    String::from_utf8(output).map_err(|error| {
+        let valid = error.utf8_error().valid_up_to();
+        let offset = escaped_bytes
+            .iter()
+            .find(|(position, _)| *position >= valid)
+            .map_or(input.len(), |(_, offset)| *offset);
+        (UnescapeErrorKind::InvalidUtf8, offset)
+    })
+}
+
+fn push_char(output: &mut Vec<u8>, character: char) {
+    let mut buffer = [0; 4];
+    output.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+}
+
+/// Escapes `input` to be the text or an attribute value of an HTML document
+pub(crate) fn escape_html(input: &str) -> String {
+    escape_markup(input, "&#39;")
+}
+
+/// Escapes `input` to be the text or an attribute value of an XML document
+pub(crate) fn escape_xml(input: &str) -> String {
+    escape_markup(input, "&apos;")
+}
+
+fn escape_markup(input: &str, apostrophe: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    for character in input.chars() {
+        match character {
+            '&' => output.push_str("&amp;"),
+            '<' => output.push_str("&lt;"),
+            '>' => output.push_str("&gt;"),
+            '"' => output.push_str("&quot;"),
+            '\'' => output.push_str(apostrophe),
+            _ => output.push(character),
+        }
+    }
+    output
+}
+
+/// Replaces the character references of HTML text, an `&` that does not start a named
+/// reference ending with `;` is kept as it is, like browsers do
+///
+/// # Errors
+///
+/// Unknown named references and numeric references that are not terminated or that are not
+/// valid code points are rejected
+pub(crate) fn unescape_html(input: &str) -> Unescaped {
+    unescape_markup(input, true)
+}
+
+/// Replaces the predefined entities and the character references of XML text
+///
+/// # Errors
+///
+/// An `&` that does not start a reference ending with `;`, a `<`, unknown entities and
+/// numeric references that are not valid code points are rejected
+pub(crate) fn unescape_xml(input: &str) -> Unescaped {
+    unescape_markup(input, false)
+}
+
+fn unescape_markup(input: &str, html: bool) -> Unescaped {
+    let mut output = String::with_capacity(input.len());
+    let specials: &[char] = if html { &['&'] } else { &['&', '<'] };
+    let mut rest = input;
+    while let Some(position) = rest.find(specials) {
+        output.push_str(&rest[..position]);
+        let offset = input.len() - rest.len() + position;
+        if rest[position..].starts_with('<') {
+            return Err((UnescapeErrorKind::UnescapedCharacter('<'), offset));
+        }
+
+        let reference = &rest[position + 1..];
+        let name_length = reference
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '#')
+            .unwrap_or(reference.len());
+        let name = &reference[..name_length];
+
+        if name.is_empty() || !reference[name_length..].starts_with(';') {
+            if html && !name.starts_with('#') {
+                output.push('&');
+                rest = reference;
+                continue;
+            }
+            return Err((UnescapeErrorKind::UnterminatedEntity, offset));
+        }
+
+        let unescaped = if let Some(number) = name.strip_prefix('#') {
+            let (digits, radix) = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => (hex, 16),
+                None => (number, 10),
+            };
+            match digits.chars().find(|c| !c.is_digit(radix)) {
+                Some(invalid) => return Err((UnescapeErrorKind::InvalidDigit(invalid), offset)),
+                None if digits.is_empty() => {
+                    return Err((UnescapeErrorKind::InvalidDigit(';'), offset));
+                }
+                None => {}
+            }
+            let code = u32::from_str_radix(digits, radix).unwrap_or(u32::MAX);
+            char::from_u32(code)
+                .filter(|&c| c != '\0')
+                .ok_or((UnescapeErrorKind::InvalidCodePoint(code), offset))?
+        } else {
+            let html_entities = if html { HTML_ENTITIES } else { &[] };
+            let known = XML_ENTITIES
+                .iter()
+                .chain(html_entities)
+                .find(|(entity, _)| *entity == name);
+            match known {
+                Some((_, character)) => *character,
+                // Like browsers, an unknown reference is only text that happens to contain `&`
+                None if html => {
+                    output.push('&');
+                    rest = reference;
+                    continue;
+                }
+                None => return Err((UnescapeErrorKind::UnknownEntity(name.to_string()), offset)),
+            }
+        };
+        output.push(unescaped);
+        rest = &reference[name_length + 1..];
+    }
+    output.push_str(rest);
+    Ok(output)
+}
+
+/// Quotes `input` to be a single word of a POSIX shell, it is left as it is when it is only
+/// made of characters that are never special to the shell
+pub(crate) fn shell_quote(input: &str) -> String {
+    let is_safe = |c: char| c.is_ascii_alphanumeric() || "@%+=:,./_-".contains(c);
+    if !input.is_empty() && input.chars().all(is_safe) {
+        return input.to_string();
+    }
+    format!("'{}'", input.replace('\'', r"'\''"))
+}
+
+/// Reads a single word of a POSIX shell, with its single quotes, double quotes and backslashes
+///
+/// # Errors
+///
+/// Unterminated quotes, a trailing backslash and the characters that would make the shell
+/// split or expand the word, unquoted whitespace, operators, globs, `$` and `` ` ``, are
+/// rejected, as are a leading `~` that expands to a home directory and a leading `#` that
+/// starts a comment
+pub(crate) fn unescape_shell(input: &str) -> Unescaped {
+    if let Some(special @ ('~' | '#')) = input.chars().next() {
+        return Err((UnescapeErrorKind::UnescapedCharacter(special), 0));
+    }
+
+    let mut output = String::with_capacity(input.len());
+    let mut characters = input.char_indices();
+    while let Some((offset, character)) = characters.next() {
+        match character {
+            '\'' => loop {
+                match characters.next() {
+                    Some((_, '\'')) => break,
+                    Some((_, quoted)) => output.push(quoted),
+                    None => return Err((UnescapeErrorKind::UnterminatedQuote, offset)),
+                }
+            },
+            '"' => loop {
+                match characters.next() {
+                    Some((_, '"')) => break,
+                    Some((_, '\\')) => match characters.next() {
+                        Some((_, '\n')) => {}
+                        Some((_, escaped @ ('$' | '`' | '"' | '\\'))) => output.push(escaped),
+                        Some((_, other)) => {
+                            output.push('\\');
+                            output.push(other);
+                        }
+                        None => return Err((UnescapeErrorKind::UnterminatedQuote, offset)),
+                    },
+                    Some((position, special @ ('$' | '`'))) => {
+                        return Err((UnescapeErrorKind::UnescapedCharacter(special), position));
+                    }
+                    Some((_, quoted)) => output.push(quoted),
+                    None => return Err((UnescapeErrorKind::UnterminatedQuote, offset)),
+                }
+            },
+            '\\' => match characters.next() {
+                Some((_, '\n')) => {}
+                Some((_, escaped)) => output.push(escaped),
+                None => return Err((UnescapeErrorKind::TruncatedEscape, offset)),
+            },
+            '|' | '&' | ';' | '<' | '>' | '(' | ')' | '$' | '`' | '*' | '?' | '[' => {
+                return Err((UnescapeErrorKind::UnescapedCharacter(character), offset));
+            }
+            _ if character.is_whitespace() => {
+                return Err((UnescapeErrorKind::UnescapedCharacter(character), offset));
+            }
+            _ => output.push(character),
+        }
+    }
+    Ok(output)
+}
+
+/// Reads exactly `count` hexadecimal digits of the escape that starts at the byte `offset`
+fn read_hex(
+    characters: &mut impl Iterator<Item = (usize, char)>,
+    offset: usize,
+    count: usize,
+) -> Result<u32, (UnescapeErrorKind, usize)> {
+    let mut value = 0_u32;
+    for _ in 0..count {
+        let (_, character) = characters
+            .next()
+            .ok_or((UnescapeErrorKind::TruncatedEscape, offset))?;
+        let digit = character
+            .to_digit(16)
+            .ok_or((UnescapeErrorKind::InvalidDigit(character), offset))?;
+        value = value << 4 | digit;
+    }
+    Ok(value)
+}
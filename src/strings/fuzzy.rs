@@ -0,0 +1,144 @@
+//! Fuzzy subsequence matching that scores how well a pattern fits a string
+
+use super::methods::{CaseOptions, FuzzyMatch};
+use super::unicode::fold_case;
+use super::words::split_words;
+
+/// Score of every matched character
+const MATCH: i32 = 16;
+/// Bonus for a match at the start of a word that follows a separator or the start of the string
+const BOUNDARY_BONUS: i32 = 8;
+/// Bonus for a match at a camel hump, such as the `B` of `fooBar`
+const CAMEL_BONUS: i32 = 7;
+/// Minimum bonus for a match right after the previous match, which otherwise keeps the bonus
+/// of the first match of the run
+const CONSECUTIVE_BONUS: i32 = 4;
+/// The bonus of the position matched by the first character of the pattern is multiplied by this
+const FIRST_CHARACTER_MULTIPLIER: i32 = 2;
+/// Penalty for the first skipped character between two matches
+const GAP_START: i32 = 3;
+/// Penalty for every other skipped character between two matches
+const GAP_EXTENSION: i32 = 1;
+
+/// Finds the best placement of the characters of `pattern`, in order, inside `input`
+///
+/// The search is case-insensitive unless `pattern` contains an uppercase character. Every
+/// placement is scored, matches at word starts as found by the case tokenizer and consecutive
+/// matches earn bonuses while gaps between matches cost points, and the best one is kept
+pub(crate) fn fuzzy_match(input: &str, pattern: &str) -> Option<FuzzyMatch> {
+    let case_sensitive = pattern.chars().any(char::is_uppercase);
+    let pattern: Vec<char> = pattern.chars().collect();
+    let characters: Vec<char> = input.chars().collect();
+    if pattern.is_empty() {
+        return Some(FuzzyMatch {
+            score: 0,
+            positions: Vec::new(),
+        });
+    }
+    if pattern.len() > characters.len() {
+        return None;
+    }
+
+    let bonuses = word_start_bonuses(input, &characters);
+    let width = characters.len();
+    // scores[row * width + column] is the best score of the first `row + 1` characters of the
+    // pattern with the last one matched at `column`, origins keeps the column of the previous
+    // match and runs the bonus of the first match of the current run of consecutive matches
+    let mut scores: Vec<Option<i32>> = vec![None; pattern.len() * width];
+    let mut origins = vec![0; pattern.len() * width];
+    let mut runs = vec![0; pattern.len() * width];
+
+    for (row, &wanted) in pattern.iter().enumerate() {
+        // Best score of the previous row followed by a gap, with the column it came from
+        let mut gapped: Option<(i32, usize)> = None;
+        for column in row..width {
+            if row > 0 && column >= 2 {
+                let skipped = scores[(row - 1) * width + column - 2]
+                    .map(|score| (score - GAP_START, column - 2));
+                let extended = gapped.map(|(score, origin)| (score - GAP_EXTENSION, origin));
+                gapped = best_of(skipped, extended);
+            }
+
+            if !characters_match(characters[column], wanted, case_sensitive) {
+                continue;
+            }
+
+            let cell = row * width + column;
+            let bonus = bonuses[column];
+            if row == 0 {
+                scores[cell] = Some(MATCH + bonus * FIRST_CHARACTER_MULTIPLIER);
+                runs[cell] = bonus;
+                continue;
+            }
+
+            let previous = cell - width - 1;
+            let run = runs[previous].max(bonus).max(CONSECUTIVE_BONUS);
+            let consecutive = scores[previous].map(|score| (score + MATCH + run, column - 1));
+            let after_gap = gapped.map(|(score, origin)| (score + MATCH + bonus, origin));
+            if let Some((score, origin)) = best_of(consecutive, after_gap) {
+                scores[cell] = Some(score);
+                origins[cell] = origin;
+                runs[cell] = if origin == column - 1 { run } else { bonus };
+            }
+        }
+    }
+
+    let last_row = (pattern.len() - 1) * width;
+    let (mut column, score) = (0..width)
+        .filter_map(|column| scores[last_row + column].map(|score| (column, score)))
+        .max_by_key(|&(column, score)| (score, std::cmp::Reverse(column)))?;
+
+    let mut positions = vec![0; pattern.len()];
+    for row in (0..pattern.len()).rev() {
+        positions[row] = column;
+        column = origins[row * width + column];
+    }
+    Some(FuzzyMatch { score, positions })
+}
+
+/// Returns the bonus earned by a match at each character of `input`, the characters that start
+/// a word of [`split_words`] earn one
+fn word_start_bonuses(input: &str, characters: &[char]) -> Vec<i32> {
+    let mut bonuses = vec![0; characters.len()];
+    let options = CaseOptions::new();
+    let mut words = split_words(input, &options).peekable();
+
+    for (index, (offset, _)) in input.char_indices().enumerate() {
+        let Some(word) = words.peek() else {
+            break;
+        };
+        if word.as_ptr() != input[offset..].as_ptr() {
+            continue;
+        }
+        words.next();
+        let follows_word = index > 0 && characters[index - 1].is_alphanumeric();
+        bonuses[index] = if follows_word {
+            CAMEL_BONUS
+        } else {
+            BOUNDARY_BONUS
+        };
+    }
+    bonuses
+}
+
+/// Returns the candidate with the highest score, `first` wins ties
+fn best_of(first: Option<(i32, usize)>, second: Option<(i32, usize)>) -> Option<(i32, usize)> {
+    match (first, second) {
+        (Some(left), Some(right)) => Some(if right.0 > left.0 { right } else { left }),
+        (left, right) => left.or(right),
+    }
+}
+
+/// Checks if `character` of the input matches `wanted` of the pattern, without case sensitivity
+/// their whole case foldings must be equal, so `ς` matches `σ` but `ß` never matches `s`
+///
+/// The dotted capital `İ` is the exception and matches `i`, since its folding `i\u{307}` could
+/// never be typed in a pattern of single characters otherwise
+fn characters_match(character: char, wanted: char, case_sensitive: bool) -> bool {
+    let fold = |character: char| {
+        let dotless = (character == 'İ').then_some('i');
+        let folded = dotless.is_none().then(|| fold_case(character));
+        dotless.into_iter().chain(folded.into_iter().flatten())
+    };
+    character == wanted || !case_sensitive && fold(character).eq(fold(wanted))
+}
@@ -0,0 +1,242 @@
+//! Compilation and matching of glob patterns for path-like strings
+
+use super::methods::GlobErrorKind;
+use super::unicode::fold_case;
+
+/// A piece of a compiled glob pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Token {
+    /// A character that must be there
+    Literal(char),
+    /// `?`, any character but `/`
+    AnyCharacter,
+    /// `*`, any run of characters without `/`
+    Star,
+    /// `[...]`, a character in or out of the ranges, but never `/`
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+    /// `**/`, any number of whole path segments, each followed by its `/`
+    Segments,
+    /// `**` at the end of the pattern, anything including `/`
+    Everything,
+}
+
+/// Compiles `pattern` into tokens
+///
+/// # Errors
+///
+/// Returns the byte offset of a `[` that is never closed or of a trailing backslash
+pub(crate) fn compile(pattern: &str) -> Result<Vec<Token>, (GlobErrorKind, usize)> {
+    let mut tokens = Vec::new();
+    let mut characters = pattern.char_indices().peekable();
+    while let Some((offset, character)) = characters.next() {
+        let token = match character {
+            '?' => Token::AnyCharacter,
+            '*' if characters.next_if(|&(_, next)| next == '*').is_some() => {
+                while characters.next_if(|&(_, next)| next == '*').is_some() {}
+                let segment_start = offset == 0 || pattern[..offset].ends_with('/');
+                match characters.peek() {
+                    Some((_, '/')) if segment_start => {
+                        characters.next();
+                        Token::Segments
+                    }
+                    None if segment_start => Token::Everything,
+                    _ => Token::Star,
+                }
+            }
+            '*' => Token::Star,
+            '[' => {
+                let end = class_end(&pattern[offset + 1..])
+                    .ok_or((GlobErrorKind::UnclosedClass, offset))?;
+                let class = &pattern[offset + 1..offset + 1 + end];
+                while characters
+                    .next_if(|&(index, _)| index <= offset + end + 1)
+                    .is_some()
+                {}
+                compile_class(class)
+            }
+            '\\' => {
+                let (_, escaped) = characters
+                    .next()
+                    .ok_or((GlobErrorKind::TrailingEscape, offset))?;
+                Token::Literal(escaped)
+            }
+            _ => Token::Literal(character),
+        };
+        tokens.push(token);
+    }
+    Ok(tokens)
+}
+
+/// Returns the byte offset of the `]` that closes a class whose content starts `class`, a `]`
+/// right after the opening `[` or `[!` is part of the class
+fn class_end(class: &str) -> Option<usize> {
+    let start = class.strip_prefix(['!', '^']).map_or(0, |_| 1);
+    let start = if class[start..].starts_with(']') {
+        start + 1
+    } else {
+        start
+    };
+    let mut characters = class.char_indices().skip_while(|&(index, _)| index < start);
+    while let Some((index, character)) = characters.next() {
+        match character {
+            ']' => return Some(index),
+            '\\' => {
+                characters.next()?;
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Compiles the content of a class, between its brackets
+fn compile_class(class: &str) -> Token {
+    let (negated, class) = match class.strip_prefix(['!', '^']) {
+        Some(rest) => (true, rest),
+        None => (false, class),
+    };
+
+    // Every member with a flag telling if it was escaped, an escaped `-` never makes a range
+    let mut members = Vec::new();
+    let mut characters = class.chars();
+    while let Some(character) = characters.next() {
+        match character {
+            '\\' => members.extend(characters.next().map(|escaped| (escaped, true))),
+            _ => members.push((character, false)),
+        }
+    }
+
+    let mut ranges = Vec::new();
+    let mut index = 0;
+    while index < members.len() {
+        let (start, _) = members[index];
+        if let (Some(('-', false)), Some(&(end, _))) =
+            (members.get(index + 1), members.get(index + 2))
+        {
+            ranges.push((start, end));
+            index += 3;
+        } else {
+            ranges.push((start, start));
+            index += 1;
+        }
+    }
+    Token::Class { negated, ranges }
+}
+
+/// Returns the tokens to match the case folded input, only literals are case folded, classes
+/// keep their bounds and compare the characters of the input in all their cases instead, see
+/// [`is_match`]
+pub(crate) fn fold_tokens(tokens: &[Token]) -> Vec<Token> {
+    let mut folded = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        match token {
+            Token::Literal(character) => {
+                folded.extend(fold_case(*character).map(Token::Literal));
+            }
+            other => folded.push(other.clone()),
+        }
+    }
+    folded
+}
+
+/// Checks if `tokens` match the whole of `input`
+///
+/// When `case_insensitive`, `tokens` come from [`fold_tokens`] and the input is case folded
+/// too, literals are compared with the folded input while `?`, `*` and classes always take
+/// whole characters of `input`, so `?` matches `ß` even though it folds to `ss`
+pub(crate) fn is_match(tokens: &[Token], input: &str, case_insensitive: bool) -> bool {
+    // Every folded character, with the original character and the length of its folding at
+    // the first folded character of each original one
+    let mut characters: Vec<(char, Option<(char, usize)>)> = Vec::with_capacity(input.len());
+    for original in input.chars() {
+        let start = characters.len();
+        if case_insensitive {
+            characters.extend(fold_case(original).map(|folded| (folded, None)));
+        } else {
+            characters.push((original, None));
+        }
+        let length = characters.len() - start;
+        if let Some((_, first)) = characters.get_mut(start) {
+            *first = Some((original, length));
+        }
+    }
+
+    let width = characters.len() + 1;
+    // matches[token * width + character] tells if the tokens from `token` on match the
+    // characters from `character` on, it is filled from the end
+    let mut matches = vec![false; (tokens.len() + 1) * width];
+    matches[tokens.len() * width + characters.len()] = true;
+
+    for (token_index, token) in tokens.iter().enumerate().rev() {
+        let row = token_index * width;
+        let next_row = row + width;
+        for index in (0..=characters.len()).rev() {
+            let character = characters.get(index).map(|&(folded, _)| folded);
+            // The original character starting here and the index right after its folding
+            let whole = characters
+                .get(index)
+                .and_then(|&(_, original)| original)
+                .filter(|&(original, _)| original != '/')
+                .map(|(original, length)| (original, index + length));
+            matches[row + index] = match token {
+                Token::Literal(literal) => {
+                    character == Some(*literal) && matches[next_row + index + 1]
+                }
+                Token::AnyCharacter => whole.is_some_and(|(_, after)| matches[next_row + after]),
+                Token::Class { negated, ranges } => whole.is_some_and(|(original, after)| {
+                    class_contains(ranges, original, case_insensitive) != *negated
+                        && matches[next_row + after]
+                }),
+                Token::Star => {
+                    matches[next_row + index]
+                        || whole.is_some_and(|(_, after)| matches[row + after])
+                }
+                Token::Everything => {
+                    matches[next_row + index] || character.is_some() && matches[row + index + 1]
+                }
+                Token::Segments => {
+                    matches[next_row + index]
+                        || characters[index..]
+                            .iter()
+                            .position(|&(c, _)| c == '/')
+                            .is_some_and(|slash| matches[row + index + slash + 1])
+                }
+            };
+        }
+    }
+    matches[0]
+}
+
+/// Checks if `character` is in one of `ranges`, without case sensitivity a single member also
+/// matches the characters with the same case folding and a range matches when `character`
+/// or one of its other cases is in it, so `[A-z]` still matches `_`
+fn class_contains(ranges: &[(char, char)], character: char, case_insensitive: bool) -> bool {
+    ranges.iter().any(|&(start, end)| {
+        if (start..=end).contains(&character) {
+            return true;
+        }
+        if !case_insensitive {
+            return false;
+        }
+        if start == end {
+            return fold_case(character).eq(fold_case(start));
+        }
+        [
+            single(fold_case(character)),
+            single(character.to_lowercase()),
+            single(character.to_uppercase()),
+        ]
+        .into_iter()
+        .flatten()
+        .any(|variant| (start..=end).contains(&variant))
+    })
+}
+
+/// Returns the only character of `characters`, if there is exactly one
+fn single(mut characters: impl Iterator<Item = char>) -> Option<char> {
+    let first = characters.next()?;
+    characters.next().is_none().then_some(first)
+}
@@ -0,0 +1,122 @@
+//! Turning any string into a valid identifier of a programming language
+
+use super::methods::{Case, Language, ToCases};
+use super::unicode::is_combining_mark;
+
+/// Strict and reserved keywords of Rust 2024, including the ones reserved for future use
+const RUST_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Rust keywords that cannot be used as raw identifiers
+const RUST_NON_RAW_KEYWORDS: &[&str] = &["crate", "self", "Self", "super"];
+
+const PYTHON_KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
+
+/// Reserved words of JavaScript in strict mode, plus `arguments` and `eval` which cannot be
+/// used as names of bindings either
+const JAVASCRIPT_KEYWORDS: &[&str] = &[
+    "arguments",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "eval",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "implements",
+    "import",
+    "in",
+    "instanceof",
+    "interface",
+    "let",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "static",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "yield",
+];
+
+/// Converts `input` to `case` and then makes it a valid identifier of `language`, see
+/// [`crate::strings::methods::ToIdentifier`] for the rules
+pub(crate) fn to_identifier(input: &str, language: Language, case: Case) -> String {
+    let converted = input.convert_case(case);
+
+    let mut identifier = String::with_capacity(converted.len() + 2);
+    if converted.starts_with(|character: char| character.is_ascii_digit()) {
+        identifier.push('_');
+    }
+
+    for character in converted.chars() {
+        if character.is_alphabetic() || character.is_ascii_digit() || is_combining_mark(character) {
+            identifier.push(character);
+        } else {
+            identifier.push('_');
+        }
+    }
+
+    // A lone `_` is the wildcard pattern of Rust and cannot name anything
+    if identifier.is_empty() || identifier == "_" {
+        identifier = if language == Language::Rust {
+            "__"
+        } else {
+            "_"
+        }
+        .to_string();
+    }
+
+    let keywords = match language {
+        Language::Rust => RUST_KEYWORDS,
+        Language::Python => PYTHON_KEYWORDS,
+        Language::JavaScript => JAVASCRIPT_KEYWORDS,
+    };
+
+    if !keywords.contains(&identifier.as_str()) {
+        return identifier;
+    }
+
+    if language == Language::Rust && !RUST_NON_RAW_KEYWORDS.contains(&identifier.as_str()) {
+        identifier.insert_str(0, "r#");
+    } else {
+        identifier.push('_');
+    }
+    identifier
+}
@@ -0,0 +1,310 @@
+//! English inflection rules used to pluralize and singularize words
+
+use super::methods::CaseOptions;
+use super::unicode::is_combining_mark;
+use super::words::split_words;
+
+/// Words that are the same in singular and plural
+const UNCOUNTABLES: &[&str] = &[
+    "deer",
+    "equipment",
+    "fish",
+    "information",
+    "jeans",
+    "means",
+    "metadata",
+    "money",
+    "moose",
+    "news",
+    "police",
+    "rice",
+    "series",
+    "sheep",
+    "species",
+];
+
+/// Pairs of singular and plural words that do not follow the suffix rules, only whole words
+/// are matched
+const IRREGULARS: &[(&str, &str)] = &[
+    ("abdomen", "abdomens"),
+    ("bus", "buses"),
+    ("caiman", "caimans"),
+    ("cookie", "cookies"),
+    ("criterion", "criteria"),
+    ("datum", "data"),
+    ("foot", "feet"),
+    ("german", "germans"),
+    ("goose", "geese"),
+    ("human", "humans"),
+    ("medium", "media"),
+    ("menu", "menus"),
+    ("mouse", "mice"),
+    ("movie", "movies"),
+    ("omen", "omens"),
+    ("ottoman", "ottomans"),
+    ("ox", "oxen"),
+    ("pie", "pies"),
+    ("quiz", "quizzes"),
+    ("roman", "romans"),
+    ("shaman", "shamans"),
+    ("specimen", "specimens"),
+    ("talisman", "talismans"),
+    ("tie", "ties"),
+    ("tooth", "teeth"),
+    ("valve", "valves"),
+    ("zombie", "zombies"),
+];
+
+/// Pairs of singular and plural words that do not follow the suffix rules, also matched at the
+/// end of compound words such as `salesman` and `grandchild`, unless the whole word is one of
+/// the [`IRREGULARS`] such as `human`
+const COMPOUND_IRREGULARS: &[(&str, &str)] = &[
+    ("child", "children"),
+    ("person", "people"),
+    ("woman", "women"),
+    ("man", "men"),
+];
+
+/// Suffix rules to pluralize a word, tried in order, the first suffix the word ends with is
+/// replaced by its plural
+const PLURAL_RULES: &[(&str, &str)] = &[
+    ("matrix", "matrices"),
+    ("vertex", "vertices"),
+    ("index", "indices"),
+    ("octopus", "octopi"),
+    ("alias", "aliases"),
+    ("atlas", "atlases"),
+    ("bias", "biases"),
+    ("canvas", "canvases"),
+    ("gas", "gases"),
+    ("lens", "lenses"),
+    ("us", "uses"),
+    ("buffalo", "buffaloes"),
+    ("tomato", "tomatoes"),
+    ("potato", "potatoes"),
+    ("hero", "heroes"),
+    ("echo", "echoes"),
+    ("sis", "ses"),
+    ("ife", "ives"),
+    ("wolf", "wolves"),
+    ("alf", "alves"),
+    ("elf", "elves"),
+    ("leaf", "leaves"),
+    ("loaf", "loaves"),
+    ("x", "xes"),
+    ("ch", "ches"),
+    ("sh", "shes"),
+    ("ss", "sses"),
+    ("zz", "zzes"),
+    ("quy", "quies"),
+    ("ay", "ays"),
+    ("ey", "eys"),
+    ("iy", "iys"),
+    ("oy", "oys"),
+    ("uy", "uys"),
+    ("yy", "yys"),
+    ("y", "ies"),
+    ("s", "s"),
+    ("", "s"),
+];
+
+/// Suffix rules to singularize a word, tried in order like [`PLURAL_RULES`]
+///
+/// Plurals ending in `uses` are ambiguous, `bonuses` comes from `bonus` but `abuses` from
+/// `abuse`, so the words ending in `us` are listed and every other one loses only its `s`
+const SINGULAR_RULES: &[(&str, &str)] = &[
+    ("matrices", "matrix"),
+    ("vertices", "vertex"),
+    ("indices", "index"),
+    ("octopi", "octopus"),
+    ("aliases", "alias"),
+    ("atlases", "atlas"),
+    ("biases", "bias"),
+    ("canvases", "canvas"),
+    ("gases", "gas"),
+    ("lenses", "lens"),
+    ("apparatuses", "apparatus"),
+    ("bonuses", "bonus"),
+    ("cactuses", "cactus"),
+    ("campuses", "campus"),
+    ("censuses", "census"),
+    ("choruses", "chorus"),
+    ("corpuses", "corpus"),
+    ("focuses", "focus"),
+    ("geniuses", "genius"),
+    ("minuses", "minus"),
+    ("nexuses", "nexus"),
+    ("omnibuses", "omnibus"),
+    ("pluses", "plus"),
+    ("prospectuses", "prospectus"),
+    ("radiuses", "radius"),
+    ("sinuses", "sinus"),
+    ("statuses", "status"),
+    ("stimuluses", "stimulus"),
+    ("syllabuses", "syllabus"),
+    ("thesauruses", "thesaurus"),
+    ("viruses", "virus"),
+    ("buffaloes", "buffalo"),
+    ("tomatoes", "tomato"),
+    ("potatoes", "potato"),
+    ("heroes", "hero"),
+    ("echoes", "echo"),
+    ("analyses", "analysis"),
+    ("diagnoses", "diagnosis"),
+    ("parentheses", "parenthesis"),
+    ("prognoses", "prognosis"),
+    ("synopses", "synopsis"),
+    ("theses", "thesis"),
+    ("knives", "knife"),
+    ("wives", "wife"),
+    ("lives", "life"),
+    ("wolves", "wolf"),
+    ("alves", "alf"),
+    ("elves", "elf"),
+    ("leaves", "leaf"),
+    ("loaves", "loaf"),
+    ("xes", "x"),
+    ("ches", "ch"),
+    ("shes", "sh"),
+    ("sses", "ss"),
+    ("ses", "se"),
+    ("zzes", "zz"),
+    ("quies", "quy"),
+    ("ies", "y"),
+    ("ss", "ss"),
+    ("us", "us"),
+    ("is", "is"),
+    ("s", ""),
+];
+
+/// All uppercase words up to this length are taken as acronyms when a suffix is appended to
+/// them, so `ID` becomes `IDs` while `USER` becomes `USERS`
+const MAX_ACRONYM_LENGTH: usize = 3;
+
+/// Which form of the word an inflection creates
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Number {
+    Singular,
+    Plural,
+}
+
+/// Inflects the last word of `input` to `number`, everything before and after that word is
+/// kept as it is
+///
+/// Words are split like the case conversions split them, so only `Status` of `HTTPStatus` and
+/// `Account` of `UserAccount` are inflected
+pub(crate) fn inflect(input: &str, number: Number) -> String {
+    let end = input
+        .trim_end_matches(|character: char| {
+            !character.is_alphanumeric() && !is_combining_mark(character)
+        })
+        .len();
+    let start = split_words(&input[..end], &CaseOptions::new())
+        .last()
+        .map_or(end, |word| end - word.len());
+
+    let word = &input[start..end];
+    let mut inflected = String::with_capacity(input.len() + 3);
+    inflected.push_str(&input[..start]);
+    inflected.push_str(&inflect_word(word, number));
+    inflected.push_str(&input[end..]);
+    inflected
+}
+
+fn inflect_word(word: &str, number: Number) -> String {
+    let lowercased = word.to_lowercase();
+
+    if word.is_empty() || UNCOUNTABLES.contains(&lowercased.as_str()) {
+        return word.to_string();
+    }
+
+    for (singular, plural) in IRREGULARS {
+        let (from, to) = match number {
+            Number::Plural => (singular, plural),
+            Number::Singular => (plural, singular),
+        };
+
+        if lowercased == *from || lowercased == *to {
+            return match_case(word, to);
+        }
+    }
+
+    for (singular, plural) in COMPOUND_IRREGULARS {
+        let (from, to) = match number {
+            Number::Plural => (singular, plural),
+            Number::Singular => (plural, singular),
+        };
+
+        if let Some((stem, ending)) = split_suffix(word, to).or_else(|| split_suffix(word, from)) {
+            return format!("{stem}{}", match_case(ending, to));
+        }
+    }
+
+    let rules = match number {
+        Number::Plural => PLURAL_RULES,
+        Number::Singular => SINGULAR_RULES,
+    };
+
+    let (stem, ending, replacement) = rules
+        .iter()
+        .find_map(|(suffix, replacement)| {
+            let (stem, ending) = split_suffix(word, suffix)?;
+            Some((stem, ending, *replacement))
+        })
+        .unwrap_or((word, "", ""));
+
+    let shouted = if ending.is_empty() {
+        is_all_uppercase(word) && word.chars().count() > MAX_ACRONYM_LENGTH
+    } else {
+        is_all_uppercase(word)
+    };
+
+    if shouted {
+        format!("{stem}{}", replacement.to_uppercase())
+    } else {
+        format!("{stem}{}", match_case(ending, replacement))
+    }
+}
+
+/// Splits `word` before `suffix` when it ends with it, ignoring the case
+fn split_suffix<'a>(word: &'a str, suffix: &str) -> Option<(&'a str, &'a str)> {
+    let cut = word.len().checked_sub(suffix.len())?;
+    let (stem, ending) = word.split_at_checked(cut)?;
+    ending
+        .eq_ignore_ascii_case(suffix)
+        .then_some((stem, ending))
+}
+
+/// Returns `replacement` written with the same case as `word`
+fn match_case(word: &str, replacement: &str) -> String {
+    if is_all_uppercase(word) {
+        return replacement.to_uppercase();
+    }
+
+    let mut matched = String::with_capacity(replacement.len());
+    let mut replacement_characters = replacement.chars();
+    if let Some(first) = replacement_characters.next() {
+        if word.starts_with(char::is_uppercase) {
+            matched.push(first.to_ascii_uppercase());
+        } else {
+            matched.push(first);
+        }
+    }
+    matched.push_str(replacement_characters.as_str());
+    matched
+}
+
+fn is_all_uppercase(word: &str) -> bool {
+    word.chars().count() > 1 && !word.contains(char::is_lowercase)
+}
+
+/// Returns the English ordinal suffix of `number`, such as `st` for `1` and `th` for `11`
+pub(crate) fn ordinal_suffix(number: u64) -> &'static str {
+    match (number % 10, number % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
+}
@@ -0,0 +1,93 @@
+//! Casing rules that depend on the language, from the conditional mappings of Unicode's
+//! `SpecialCasing.txt` and the Turkic entries of `CaseFolding.txt`
+
+use super::methods::Locale;
+use super::unicode::fold_case;
+
+const COMBINING_DOT_ABOVE: char = '\u{307}';
+
+/// Returns the lowercase form of `character` when `locale` changes it, `previous` and `next`
+/// are the characters around it
+pub(crate) fn special_lowercase(
+    locale: Locale,
+    character: char,
+    previous: Option<char>,
+    next: Option<char>,
+) -> Option<&'static str> {
+    match locale {
+        Locale::Turkish | Locale::Azerbaijani => match character {
+            'İ' => Some("i"),
+            'I' if next == Some(COMBINING_DOT_ABOVE) => Some("i"),
+            'I' => Some("ı"),
+            COMBINING_DOT_ABOVE if previous == Some('I') => Some(""),
+            _ => None,
+        },
+        Locale::Lithuanian => {
+            let more_above = next.is_some_and(is_combining_above);
+            match character {
+                'I' if more_above => Some("i\u{307}"),
+                'J' if more_above => Some("j\u{307}"),
+                'Į' if more_above => Some("į\u{307}"),
+                'Ì' => Some("i\u{307}\u{300}"),
+                'Í' => Some("i\u{307}\u{301}"),
+                'Ĩ' => Some("i\u{307}\u{303}"),
+                _ => None,
+            }
+        }
+        Locale::Root => None,
+    }
+}
+
+/// Returns the uppercase form of `character` when `locale` changes it, `previous` is the
+/// character before it, the titlecase form is the same for all these characters
+pub(crate) fn special_uppercase(
+    locale: Locale,
+    character: char,
+    previous: Option<char>,
+) -> Option<&'static str> {
+    match locale {
+        Locale::Turkish | Locale::Azerbaijani if character == 'i' => Some("İ"),
+        Locale::Lithuanian
+            if character == COMBINING_DOT_ABOVE && previous.is_some_and(is_soft_dotted) =>
+        {
+            Some("")
+        }
+        _ => None,
+    }
+}
+
+/// Returns the case folded form of `character` under `locale`, Turkish and Azerbaijani fold
+/// `I` to `ı` and `İ` to `i` instead of `i` and `i̇`
+pub(crate) fn fold_case_in(character: char, locale: Locale) -> impl Iterator<Item = char> {
+    let special = match (locale, character) {
+        (Locale::Turkish | Locale::Azerbaijani, 'I') => Some('ı'),
+        (Locale::Turkish | Locale::Azerbaijani, 'İ') => Some('i'),
+        _ => None,
+    };
+    let folded = special.is_none().then(|| fold_case(character));
+    special.into_iter().chain(folded.into_iter().flatten())
+}
+
+/// Checks if `character` is a combining mark drawn above the character before it, such as an
+/// accent or a tilde
+fn is_combining_above(character: char) -> bool {
+    matches!(
+        character,
+        '\u{300}'..='\u{314}'
+            | '\u{33D}'..='\u{344}'
+            | '\u{346}'
+            | '\u{34A}'..='\u{34C}'
+            | '\u{350}'..='\u{352}'
+            | '\u{357}'
+            | '\u{35B}'
+            | '\u{363}'..='\u{36F}'
+    )
+}
+
+/// Checks if `character` loses its dot when an accent is drawn above it
+fn is_soft_dotted(character: char) -> bool {
+    matches!(
+        character,
+        'i' | 'j' | 'į' | 'ɨ' | 'ʝ' | 'ǰ' | 'ị' | 'ḭ' | 'ɉ' | 'ⅈ' | 'ⅉ'
+    )
+}
@@ -1,4 +1,32 @@
-use std::fmt::Write;
+use std::borrow::Borrow;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::ops::{Bound, RangeBounds};
+use std::str::FromStr;
+
+use super::escaping::{
+    escape_c, escape_html, escape_json, escape_xml, shell_quote, unescape_c, unescape_html,
+    unescape_json, unescape_shell, unescape_xml,
+};
+use super::fuzzy::fuzzy_match;
+use super::globbing::{compile, fold_tokens, is_match, Token};
+use super::identifiers::to_identifier;
+use super::inflections::{inflect, ordinal_suffix, Number};
+use super::locale::fold_case_in;
+use super::natural::natural_cmp;
+use super::similarity::{damerau_levenshtein, jaro_winkler, levenshtein, normalized_similarity};
+use super::splitting::{split_quoted, split_shell_words};
+use super::templates::format_named;
+use super::transliteration::transliterate;
+use super::unicode::{fold_case, grapheme_offset, grapheme_width, graphemes, is_combining_mark};
+use super::validation::check_case;
+use super::words::{write_lowercase, write_uppercase, write_words, WordCase};
+use super::wrapping::wrap;
+
+static DEFAULT_OPTIONS: CaseOptions = CaseOptions::new();
 
 pub trait RemoveWhitespaces {
     /// Creates a new string that contains no whitespaces
@@ -19,10 +47,418 @@ pub trait RemoveWhitespaces {
     /// assert_eq!("thisisanothertestwithnormal", case2.remove_whitespaces());
     ///```
     fn remove_whitespaces(&self) -> String;
+
+    /// Creates a new string with no whitespaces except for line breaks (`\n` and `\r`), so
+    /// the lines are kept
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// let case1 = String::from("first line \n second line");
+    ///
+    /// assert_eq!("firstline\nsecondline", case1.remove_whitespaces_except_newlines());
+    ///```
+    fn remove_whitespaces_except_newlines(&self) -> String;
+
+    /// Creates a new string where every run of whitespaces, line breaks included, is replaced
+    /// by a single space
+    ///
+    /// # Note to Implementors
+    ///
+    /// The start and the end of the string are not trimmed, a run there becomes a single space
+    /// too, call `trim` on the result to remove it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!("first case with spaces", "first   case\twith \n spaces".collapse_whitespace());
+    ///
+    /// assert_eq!(" padded ", "   padded  ".collapse_whitespace());
+    ///```
+    fn collapse_whitespace(&self) -> String;
+
+    /// Creates a new string where the whitespaces at the start and the end of every line are
+    /// removed, the line breaks themselves are kept as they are
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!("first\nsecond\r\nthird", "  first  \n\tsecond \r\n third".trim_lines());
+    ///```
+    fn trim_lines(&self) -> String;
+
+    /// Creates a new string where every `\r\n` and every lone `\r` is replaced by `\n`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!("first\nsecond\nthird\n", "first\r\nsecond\rthird\n".normalize_line_endings());
+    ///```
+    fn normalize_line_endings(&self) -> String;
+
+    /// Creates a new string without the invisible zero width characters that usually come
+    /// along with pasted text: zero width space (`U+200B`), zero width non-joiner (`U+200C`),
+    /// zero width joiner (`U+200D`), word joiner (`U+2060`) and the byte order mark (`U+FEFF`)
+    ///
+    /// # Note to Implementors
+    ///
+    /// Removing the zero width joiner also splits emoji sequences such as `👩‍💻` into their
+    /// parts
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!("username", "\u{FEFF}user\u{200B}name".strip_zero_width());
+    ///```
+    fn strip_zero_width(&self) -> String;
+}
+
+/// The case styles a string can be converted to with [`ToCases::convert_case`] or detected
+/// with [`IsCases::detect_case`]
+///
+/// A `Case` can be parsed from its name, the parsing is forgiving so `"kebab"`, `"kebab-case"`
+/// and `"KebabCase"` all give [`Case::Kebab`]
+///
+/// # Examples
+///
+/// ```
+/// use crate::strings::methods::*;
+///
+/// let case: Case = "kebab".parse().unwrap();
+///
+/// assert_eq!(Case::Kebab, case);
+///
+/// assert_eq!("SCREAMING_SNAKE_CASE", Case::ScreamingSnake.to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `snake_case`
+    Snake,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+    /// `kebab-case`
+    Kebab,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+    /// `Train-Case`
+    Train,
+    /// `dot.case`
+    Dot,
+    /// `path/case`
+    Path,
+    /// `Title Case`
+    Title,
+    /// `Sentence case`
+    Sentence,
+}
+
+impl Case {
+    /// Every case, in the order [`IsCases::detect_case`] tries them
+    pub const ALL: [Case; 10] = [
+        Case::Snake,
+        Case::Kebab,
+        Case::ScreamingSnake,
+        Case::Camel,
+        Case::Pascal,
+        Case::Train,
+        Case::Dot,
+        Case::Path,
+        Case::Sentence,
+        Case::Title,
+    ];
+
+    /// Returns the name of the case written in the case itself, for example `kebab-case`
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Case::Snake => "snake_case",
+            Case::Camel => "camelCase",
+            Case::Pascal => "PascalCase",
+            Case::Kebab => "kebab-case",
+            Case::ScreamingSnake => "SCREAMING_SNAKE_CASE",
+            Case::Train => "Train-Case",
+            Case::Dot => "dot.case",
+            Case::Path => "path/case",
+            Case::Title => "Title Case",
+            Case::Sentence => "Sentence case",
+        }
+    }
+}
+
+impl Case {
+    /// Returns the separator between words, how the first word is cased and how the rest of
+    /// the words are cased
+    pub(crate) fn layout(self) -> (Option<char>, WordCase, WordCase) {
+        match self {
+            Case::Snake => (Some('_'), WordCase::Lower, WordCase::Lower),
+            Case::Camel => (None, WordCase::Lower, WordCase::Capitalized),
+            Case::Pascal => (None, WordCase::Capitalized, WordCase::Capitalized),
+            Case::Kebab => (Some('-'), WordCase::Lower, WordCase::Lower),
+            Case::ScreamingSnake => (Some('_'), WordCase::Upper, WordCase::Upper),
+            Case::Train => (Some('-'), WordCase::Capitalized, WordCase::Capitalized),
+            Case::Dot => (Some('.'), WordCase::Lower, WordCase::Lower),
+            Case::Path => (Some('/'), WordCase::Lower, WordCase::Lower),
+            Case::Title => (Some(' '), WordCase::Capitalized, WordCase::Capitalized),
+            Case::Sentence => (Some(' '), WordCase::Capitalized, WordCase::Lower),
+        }
+    }
+}
+
+impl fmt::Display for Case {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The error returned when parsing a [`Case`] from a name that is not a known case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCaseError {
+    name: String,
+}
+
+impl fmt::Display for ParseCaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown case `{}`", self.name)
+    }
+}
+
+impl Error for ParseCaseError {}
+
+impl FromStr for Case {
+    type Err = ParseCaseError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        let normalized = name.to_snake_case();
+        let normalized = normalized.strip_suffix("_case").unwrap_or(&normalized);
+
+        match normalized {
+            "snake" | "lower_snake" => Ok(Case::Snake),
+            "camel" | "lower_camel" => Ok(Case::Camel),
+            "pascal" | "upper_camel" => Ok(Case::Pascal),
+            "kebab" => Ok(Case::Kebab),
+            "screaming_snake" | "upper_snake" | "constant" => Ok(Case::ScreamingSnake),
+            "train" => Ok(Case::Train),
+            "dot" => Ok(Case::Dot),
+            "path" => Ok(Case::Path),
+            "title" => Ok(Case::Title),
+            "sentence" => Ok(Case::Sentence),
+            _ => Err(ParseCaseError {
+                name: name.to_string(),
+            }),
+        }
+    }
+}
+
+/// How a run of uppercase characters such as `HTTPServer` is split into words
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CapitalRuns {
+    /// The run is an acronym, its last capital starts the next word when followed by a
+    /// lowercase character, so `HTTPServer` becomes `HTTP` and `Server`
+    #[default]
+    Acronym,
+    /// Every uppercase character starts a new word, so `IOStream` becomes `I`, `O` and `Stream`
+    EveryCapital,
+}
+
+/// The language whose casing rules are followed, the root locale follows the default rules of
+/// Unicode which fit most languages
+///
+/// # Examples
+///
+/// ```
+/// use crate::strings::methods::*;
+///
+/// let turkish: Locale = "tr-TR".parse().unwrap();
+///
+/// assert_eq!(Locale::Turkish, turkish);
+/// assert_eq!(Locale::Root, "fr".parse().unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    /// The default rules of Unicode
+    #[default]
+    Root,
+    /// Turkish, `i` and `İ` are the lowercase and uppercase forms of the dotted i and `ı` and
+    /// `I` the forms of the dotless i
+    Turkish,
+    /// Azerbaijani, with the same dotted and dotless i as Turkish
+    Azerbaijani,
+    /// Lithuanian, the lowercase `i`, `j` and `į` keep a combining dot above when an accent
+    /// is drawn above them, which is removed again in uppercase
+    Lithuanian,
+}
+
+/// The error returned when parsing a [`Locale`] from a string that is not a language tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseLocaleError {
+    tag: String,
+}
+
+impl fmt::Display for ParseLocaleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid language tag `{}`", self.tag)
+    }
+}
+
+impl Error for ParseLocaleError {}
+
+impl FromStr for Locale {
+    type Err = ParseLocaleError;
+
+    /// Parses a language tag such as `tr`, `az-Latn-AZ` or `lt_LT`, only the language is
+    /// read and the languages without their own casing rules give [`Locale::Root`]
+    fn from_str(tag: &str) -> Result<Self, Self::Err> {
+        let language = tag.split(['-', '_']).next().unwrap_or_default();
+        if !(2..=3).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_alphabetic())
+        {
+            return Err(ParseLocaleError {
+                tag: tag.to_string(),
+            });
+        }
+
+        match language.to_ascii_lowercase().as_str() {
+            "tr" | "tur" => Ok(Locale::Turkish),
+            "az" | "aze" => Ok(Locale::Azerbaijani),
+            "lt" | "lit" => Ok(Locale::Lithuanian),
+            _ => Ok(Locale::Root),
+        }
+    }
+}
+
+/// Options for [`ToCases::convert_case_with`]
+///
+/// # Examples
+///
+/// ```
+/// use crate::strings::methods::*;
+///
+/// let options = CaseOptions::new()
+///     .capital_runs(CapitalRuns::Acronym)
+///     .acronym("ID")
+///     .acronyms(["URL", "HTTP"]);
+///
+/// let case1 = String::from("user_id_url");
+///
+/// assert_eq!("UserIDURL", case1.convert_case_with(Case::Pascal, &options));
+///
+/// assert_eq!("userIDURL", case1.convert_case_with(Case::Camel, &options));
+///
+/// let turkish = CaseOptions::new().locale(Locale::Turkish);
+///
+/// assert_eq!("İstanbul", "istanbul".convert_case_with(Case::Pascal, &turkish));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CaseOptions {
+    pub(crate) capital_runs: CapitalRuns,
+    pub(crate) acronyms: Vec<String>,
+    pub(crate) locale: Locale,
+}
+
+impl CaseOptions {
+    /// Creates the default options, runs of capitals are split with [`CapitalRuns::Acronym`],
+    /// there are no known acronyms and the casing follows [`Locale::Root`]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            capital_runs: CapitalRuns::Acronym,
+            acronyms: Vec::new(),
+            locale: Locale::Root,
+        }
+    }
+
+    /// Sets the language whose rules are followed to change the case of the characters
+    #[must_use]
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Sets how runs of uppercase characters are split into words
+    #[must_use]
+    pub fn capital_runs(mut self, capital_runs: CapitalRuns) -> Self {
+        self.capital_runs = capital_runs;
+        self
+    }
+
+    /// Adds an acronym that is kept fully uppercase in the capitalized words of `PascalCase`,
+    /// `camelCase`, `Train-Case` and `Title Case`, runs of capitals are also split after it
+    ///
+    /// Its plural with a lowercase `s`, such as `IDs` for `ID`, is a word of its own too and
+    /// keeps the acronym uppercase
+    #[must_use]
+    pub fn acronym(mut self, acronym: impl Into<String>) -> Self {
+        self.acronyms.push(acronym.into().to_uppercase());
+        self
+    }
+
+    /// Adds several acronyms, see [`CaseOptions::acronym`]
+    #[must_use]
+    pub fn acronyms<A: Into<String>>(self, acronyms: impl IntoIterator<Item = A>) -> Self {
+        acronyms.into_iter().fold(self, Self::acronym)
+    }
+
+    /// Checks if `word` is one of the known acronyms, ignoring its case
+    pub(crate) fn is_acronym(&self, word: &str) -> bool {
+        self.acronyms.iter().any(|acronym| {
+            acronym
+                .chars()
+                .eq(word.chars().flat_map(char::to_uppercase))
+        })
+    }
+
+    /// Checks if `word` is the plural of a known acronym, written with a lowercase `s` such as
+    /// `IDs` or `ids`
+    pub(crate) fn is_acronym_plural(&self, word: &str) -> bool {
+        word.strip_suffix('s')
+            .is_some_and(|acronym| !acronym.is_empty() && self.is_acronym(acronym))
+    }
+}
+
+/// Converts a string to a [`Case`] when it is displayed, it is created with
+/// [`ToCases::as_case`] and its siblings such as [`ToCases::as_snake_case`]
+///
+/// # Examples
+///
+/// ```
+/// use crate::strings::methods::*;
+///
+/// assert_eq!("user_id", "userId".as_snake_case().to_string());
+///
+/// assert_eq!("[USER_ID]", format!("[{}]", "userId".as_screaming_snake_case()));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct CaseDisplay<'a> {
+    input: &'a str,
+    case: Case,
+    options: &'a CaseOptions,
+}
+
+impl fmt::Display for CaseDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (separator, first, rest) = self.case.layout();
+        write_words(f, self.input, separator, first, rest, self.options)
+    }
 }
 
 /// Declares methods for converting strings to different cases such as pascal case or
 /// camel case
+///
+/// The input is split into words before converting it, a new word starts after whitespace,
+/// `_`, `-`, `.` or any other character that is not alphanumeric, at a camel hump such as
+/// `parseHttp` and at the end of a run of capitals such as `HTTPServer`, so strings that are
+/// already in some case can be converted to another one
 pub trait ToCases {
     /// Creates a new string in a `PascalCase` format
     ///
@@ -33,15 +469,15 @@ pub trait ToCases {
     /// use crate::strings::methods::*;
     /// let case1 = String::from("this is the first case");
     ///
-    /// assert_eq!("thisIsTheFirstCase", case1.to_camel_case());
+    /// assert_eq!("ThisIsTheFirstCase", case1.to_pascal_case());
     ///
     /// let case2 = String::from(" this is the   second case2  ");
     ///
-    /// assert_eq!("thisIsTheSecondCase2", case2.to_camel_case());
+    /// assert_eq!("ThisIsTheSecondCase2", case2.to_pascal_case());
     ///
-    /// let case3 = String::from(" This is a third case 3");
+    /// let case3 = String::from("user_id");
     ///
-    /// assert_eq!("thisIsAThirdCase3", case3.to_camel_case());
+    /// assert_eq!("UserId", case3.to_pascal_case());
     ///```
     fn to_pascal_case(&self) -> String;
     /// Creates a new string in a `snake_case` format
@@ -57,6 +493,10 @@ pub trait ToCases {
     /// let case2 = String::from(" this is the   second case2  ");
     ///
     /// assert_eq!("this_is_the_second_case2", case2.to_snake_case());
+    ///
+    /// let case3 = String::from("parseHTTPResponse");
+    ///
+    /// assert_eq!("parse_http_response", case3.to_snake_case());
     ///```
     fn to_snake_case(&self) -> String;
 
@@ -68,202 +508,2816 @@ pub trait ToCases {
     /// use crate::strings::methods::*;
     /// let case1 = String::from("this is the first case");
     ///
-    /// assert_eq!("this_is_the_first_case", case1.to_snake_case());
+    /// assert_eq!("thisIsTheFirstCase", case1.to_camel_case());
     ///
     /// let case2 = String::from(" this is the   second case2  ");
     ///
-    /// assert_eq!("this_is_the_second_case2", case2.to_snake_case());
+    /// assert_eq!("thisIsTheSecondCase2", case2.to_camel_case());
+    ///
+    /// let case3 = String::from("user-id");
+    ///
+    /// assert_eq!("userId", case3.to_camel_case());
     ///```
     fn to_camel_case(&self) -> String;
-}
 
-pub trait IsCases {
-    /// Check if `String` is of `camelCase` form
+    /// Creates a new string in a `kebab-case` format
     ///
-    /// # Note to Implementors
+    /// # Examples
     ///
-    /// The rules for a string to be `camelCase` are:
-    /// - The `String` must not be `lower_case`
-    /// - The first character must not be uppercase
-    /// - There must not be whitespaces
-    /// - All characters must be `alphabetic`
+    /// ```
+    /// use crate::strings::methods::*;
+    /// let case1 = String::from("this is the first case");
     ///
-    /// # Examples
+    /// assert_eq!("this-is-the-first-case", case1.to_kebab_case());
     ///
-    ///```
-    /// use crate::strings::methods::IsCases;
-    /// let case1 = String::from("thisIsCamelCase");
+    /// let case2 = String::from("dryRun");
     ///
-    /// assert!(case1.is_camel_case());
-    /// let case2 = String::from("ThisisNotCamelCase");
+    /// assert_eq!("dry-run", case2.to_kebab_case());
+    ///```
+    fn to_kebab_case(&self) -> String;
+
+    /// Creates a new string in a `SCREAMING_SNAKE_CASE` format
     ///
-    /// assert!(!case2.is_camel_case());
-    /// let case3 = String::from("this isnot_camel_case");
+    /// # Examples
     ///
-    /// assert!(!case3.is_camel_case());
-    /// let case4 = String::from("this#isnotCamelcase");
+    /// ```
+    /// use crate::strings::methods::*;
+    /// let case1 = String::from("databaseUrl");
     ///
-    /// assert!(!case4.is_camel_case());
-    /// let case5 = String::from("thisIs%&notcamelcase");
+    /// assert_eq!("DATABASE_URL", case1.to_screaming_snake_case());
     ///
-    /// assert!(!case5.is_camel_case());
-    /// let case6 = String::from("thisisnotcamelcase");
+    /// let case2 = String::from("straße name");
     ///
-    /// assert!(!case6.is_camel_case());
+    /// assert_eq!("STRASSE_NAME", case2.to_screaming_snake_case());
     ///```
-    fn is_camel_case(&self) -> bool;
+    fn to_screaming_snake_case(&self) -> String;
 
-    /// Check if `String` is of a `PascalCase` form
+    /// Creates a new string in a `Train-Case` format, which is the format used by HTTP headers
     ///
-    /// # Note to Implementors
+    /// # Examples
     ///
-    /// The rules for a string to be `PascalCase` are:
-    /// - The `String` must not be `lower_case`
-    /// - The first character must be uppercase
-    /// - There must not be whitespaces
-    /// - All characters must be `alphabetic`
+    /// ```
+    /// use crate::strings::methods::*;
+    /// let case1 = String::from("content_type");
+    ///
+    /// assert_eq!("Content-Type", case1.to_train_case());
+    ///```
+    fn to_train_case(&self) -> String;
+
+    /// Creates a new string in a `dot.case` format
     ///
     /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    /// let case1 = String::from("ServerMaxConnections");
+    ///
+    /// assert_eq!("server.max.connections", case1.to_dot_case());
     ///```
-    /// use crate::strings::methods::IsCases;
-    /// let case1 = String::from("ThisIsPascalCase");
+    fn to_dot_case(&self) -> String;
+
+    /// Creates a new string in a `path/case` format
     ///
-    /// assert!(case1.is_pascal_case());
+    /// # Examples
     ///
-    /// let case2 = String::from("thisisNotPascalCase");
+    /// ```
+    /// use crate::strings::methods::*;
+    /// let case1 = String::from("UserAccountSettings");
     ///
-    /// assert!(!case2.is_pascal_case());
+    /// assert_eq!("user/account/settings", case1.to_path_case());
+    ///```
+    fn to_path_case(&self) -> String;
+
+    /// Creates a new string in a `Title Case` format, every word is capitalized and separated by
+    /// a single space
     ///
-    /// let case3 = String::from("this isnot_pascal_case");
+    /// # Examples
     ///
-    /// assert!(!case3.is_pascal_case());
+    /// ```
+    /// use crate::strings::methods::*;
+    /// let case1 = String::from("user_account_settings");
     ///
-    /// let case4 = String::from("this#isnotPascalcase");
+    /// assert_eq!("User Account Settings", case1.to_title_case());
+    ///```
+    fn to_title_case(&self) -> String;
+
+    /// Creates a new string in a `Sentence case` format, only the first word is capitalized and
+    /// words are separated by a single space
     ///
-    /// assert!(!case4.is_pascal_case());
+    /// # Examples
     ///
-    /// let case5 = String::from("thisIs%&notpascalcase");
-    /// assert!(!case5.is_pascal_case());
+    /// ```
+    /// use crate::strings::methods::*;
+    /// let case1 = String::from("userAccountSettings");
     ///
-    /// let case6 = String::from("thisisnotpascalcase");
-    /// assert!(!case6.is_pascal_case());
-    ///````
-    fn is_pascal_case(&self) -> bool;
-}
+    /// assert_eq!("User account settings", case1.to_sentence_case());
+    ///```
+    fn to_sentence_case(&self) -> String;
 
-/// Declares methods for obtaining part of a string at a certain position
-pub trait SelectNth {
-    /// Obtains the first character of a string
+    /// Creates a new string in the given [`Case`], useful when the case is only known at
+    /// runtime, for example when it comes from a configuration file
     ///
     /// # Examples
     ///
+    /// ```
+    /// use crate::strings::methods::*;
+    /// let case1 = String::from("userAccount");
+    ///
+    /// assert_eq!("user-account", case1.convert_case(Case::Kebab));
     ///
+    /// let case: Case = "snake_case".parse().unwrap();
+    ///
+    /// assert_eq!("user_account", case1.convert_case(case));
     ///```
-    /// use crate::strings::methods::SelectNth;
+    fn convert_case(&self, case: Case) -> String {
+        self.convert_case_with(case, &DEFAULT_OPTIONS)
+    }
+
+    /// Creates a new string in the given [`Case`] following `options`, which control how runs of
+    /// capitals are split into words and which acronyms stay uppercase
     ///
-    /// let case1 = String::from("obtain the first character");
+    /// # Examples
     ///
-    /// assert_eq!("o", case1.first());
+    /// ```
+    /// use crate::strings::methods::*;
+    /// let case1 = String::from("xml http request");
+    ///
+    /// assert_eq!("XmlHttpRequest", case1.to_pascal_case());
+    ///
+    /// let options = CaseOptions::new().acronyms(["XML", "HTTP"]);
+    ///
+    /// assert_eq!("XMLHTTPRequest", case1.convert_case_with(Case::Pascal, &options));
     ///
-    /// assert_eq!("Helo world", case2.first());
+    /// let case2 = String::from("XMLHTTPRequest");
+    ///
+    /// assert_eq!("xml_http_request", case2.convert_case_with(Case::Snake, &options));
     ///```
+    fn convert_case_with(&self, case: Case, options: &CaseOptions) -> String;
+
+    /// Writes the string converted to the given [`Case`] into `output` instead of allocating a
+    /// new `String`, following `options` like [`ToCases::convert_case_with`]
     ///
-    fn first(&self) -> String;
-}
+    /// # Errors
+    ///
+    /// Returns an error only if writing into `output` fails
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    /// let mut output = String::new();
+    ///
+    /// "userId".write_case_with(Case::Snake, &CaseOptions::new(), &mut output).unwrap();
+    ///
+    /// assert_eq!("user_id", output);
+    ///```
+    fn write_case_with(
+        &self,
+        case: Case,
+        options: &CaseOptions,
+        output: &mut impl fmt::Write,
+    ) -> fmt::Result;
 
-impl SelectNth for String {
-    fn first(&self) -> String {
-        self.chars().nth(0).unwrap_or_default().to_string()
+    /// Writes the string converted to the given [`Case`] into `output`, see
+    /// [`ToCases::write_case_with`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing into `output` fails
+    fn write_case(&self, case: Case, output: &mut impl fmt::Write) -> fmt::Result {
+        self.write_case_with(case, &DEFAULT_OPTIONS, output)
     }
-}
 
-impl SelectNth for &str {
-    fn first(&self) -> String {
-        self.chars().nth(0).unwrap_or_default().to_string()
+    /// Like [`ToCases::to_snake_case`] but writes into `output` instead of allocating
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing into `output` fails
+    fn write_snake_case(&self, output: &mut impl fmt::Write) -> fmt::Result {
+        self.write_case(Case::Snake, output)
     }
-}
 
-impl RemoveWhitespaces for String {
-    fn remove_whitespaces(&self) -> String {
-        let mut new_string_with_no_white_spaces = String::with_capacity(self.capacity());
-        for character in self.chars() {
-            if !character.is_whitespace() {
-                new_string_with_no_white_spaces.push(character);
-            }
-        }
-        new_string_with_no_white_spaces
+    /// Like [`ToCases::to_camel_case`] but writes into `output` instead of allocating
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing into `output` fails
+    fn write_camel_case(&self, output: &mut impl fmt::Write) -> fmt::Result {
+        self.write_case(Case::Camel, output)
     }
-}
 
-impl ToCases for String {
-    fn to_snake_case(&self) -> String {
-        let mut camel_cased = String::with_capacity(self.capacity());
-        for word in self.split_whitespace() {
-            camel_cased.push_str(format!("{word}_").as_str());
-        }
-        camel_cased.pop();
-        camel_cased
+    /// Like [`ToCases::to_pascal_case`] but writes into `output` instead of allocating
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing into `output` fails
+    fn write_pascal_case(&self, output: &mut impl fmt::Write) -> fmt::Result {
+        self.write_case(Case::Pascal, output)
     }
 
-    fn to_camel_case(&self) -> String {
-        let mut words = self.split_whitespace();
+    /// Like [`ToCases::to_kebab_case`] but writes into `output` instead of allocating
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing into `output` fails
+    fn write_kebab_case(&self, output: &mut impl fmt::Write) -> fmt::Result {
+        self.write_case(Case::Kebab, output)
+    }
+
+    /// Like [`ToCases::to_screaming_snake_case`] but writes into `output` instead of allocating
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing into `output` fails
+    fn write_screaming_snake_case(&self, output: &mut impl fmt::Write) -> fmt::Result {
+        self.write_case(Case::ScreamingSnake, output)
+    }
+
+    /// Like [`ToCases::to_train_case`] but writes into `output` instead of allocating
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing into `output` fails
+    fn write_train_case(&self, output: &mut impl fmt::Write) -> fmt::Result {
+        self.write_case(Case::Train, output)
+    }
+
+    /// Like [`ToCases::to_dot_case`] but writes into `output` instead of allocating
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing into `output` fails
+    fn write_dot_case(&self, output: &mut impl fmt::Write) -> fmt::Result {
+        self.write_case(Case::Dot, output)
+    }
+
+    /// Like [`ToCases::to_path_case`] but writes into `output` instead of allocating
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing into `output` fails
+    fn write_path_case(&self, output: &mut impl fmt::Write) -> fmt::Result {
+        self.write_case(Case::Path, output)
+    }
+
+    /// Like [`ToCases::to_title_case`] but writes into `output` instead of allocating
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing into `output` fails
+    fn write_title_case(&self, output: &mut impl fmt::Write) -> fmt::Result {
+        self.write_case(Case::Title, output)
+    }
+
+    /// Like [`ToCases::to_sentence_case`] but writes into `output` instead of allocating
+    ///
+    /// # Errors
+    ///
+    /// Returns an error only if writing into `output` fails
+    fn write_sentence_case(&self, output: &mut impl fmt::Write) -> fmt::Result {
+        self.write_case(Case::Sentence, output)
+    }
+
+    /// Returns a value that converts the string to the given [`Case`] following `options` when
+    /// it is displayed, so the conversion happens lazily at format time without allocating
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    /// let options = CaseOptions::new().acronym("ID");
+    ///
+    /// let line = format!("{}: {}", "userId".as_case_with(Case::Pascal, &options), 1);
+    ///
+    /// assert_eq!("UserID: 1", line);
+    ///```
+    fn as_case_with<'a>(&'a self, case: Case, options: &'a CaseOptions) -> CaseDisplay<'a>;
+
+    /// Returns a value that converts the string to the given [`Case`] when it is displayed, see
+    /// [`ToCases::as_case_with`]
+    fn as_case(&self, case: Case) -> CaseDisplay<'_> {
+        self.as_case_with(case, &DEFAULT_OPTIONS)
+    }
+
+    /// Like [`ToCases::to_snake_case`] but converts lazily when displayed, see
+    /// [`ToCases::as_case_with`]
+    fn as_snake_case(&self) -> CaseDisplay<'_> {
+        self.as_case(Case::Snake)
+    }
+
+    /// Like [`ToCases::to_camel_case`] but converts lazily when displayed, see
+    /// [`ToCases::as_case_with`]
+    fn as_camel_case(&self) -> CaseDisplay<'_> {
+        self.as_case(Case::Camel)
+    }
+
+    /// Like [`ToCases::to_pascal_case`] but converts lazily when displayed, see
+    /// [`ToCases::as_case_with`]
+    fn as_pascal_case(&self) -> CaseDisplay<'_> {
+        self.as_case(Case::Pascal)
+    }
+
+    /// Like [`ToCases::to_kebab_case`] but converts lazily when displayed, see
+    /// [`ToCases::as_case_with`]
+    fn as_kebab_case(&self) -> CaseDisplay<'_> {
+        self.as_case(Case::Kebab)
+    }
+
+    /// Like [`ToCases::to_screaming_snake_case`] but converts lazily when displayed, see
+    /// [`ToCases::as_case_with`]
+    fn as_screaming_snake_case(&self) -> CaseDisplay<'_> {
+        self.as_case(Case::ScreamingSnake)
+    }
+
+    /// Like [`ToCases::to_train_case`] but converts lazily when displayed, see
+    /// [`ToCases::as_case_with`]
+    fn as_train_case(&self) -> CaseDisplay<'_> {
+        self.as_case(Case::Train)
+    }
+
+    /// Like [`ToCases::to_dot_case`] but converts lazily when displayed, see
+    /// [`ToCases::as_case_with`]
+    fn as_dot_case(&self) -> CaseDisplay<'_> {
+        self.as_case(Case::Dot)
+    }
+
+    /// Like [`ToCases::to_path_case`] but converts lazily when displayed, see
+    /// [`ToCases::as_case_with`]
+    fn as_path_case(&self) -> CaseDisplay<'_> {
+        self.as_case(Case::Path)
+    }
+
+    /// Like [`ToCases::to_title_case`] but converts lazily when displayed, see
+    /// [`ToCases::as_case_with`]
+    fn as_title_case(&self) -> CaseDisplay<'_> {
+        self.as_case(Case::Title)
+    }
+
+    /// Like [`ToCases::to_sentence_case`] but converts lazily when displayed, see
+    /// [`ToCases::as_case_with`]
+    fn as_sentence_case(&self) -> CaseDisplay<'_> {
+        self.as_case(Case::Sentence)
+    }
+}
+
+/// Where digits are allowed to appear when checking if a string is in a [`Case`] with
+/// [`IsCases::is_case`]
+///
+/// # Examples
+///
+/// ```
+/// use crate::strings::methods::*;
+///
+/// let case1 = String::from("api_2_key");
+///
+/// assert!(case1.is_case(Case::Snake, DigitRule::NotLeading));
+///
+/// assert!(!case1.is_case(Case::Snake, DigitRule::NotWordStart));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DigitRule {
+    /// Digits are not allowed at all
+    Forbidden,
+    /// Digits are allowed anywhere except as the first character of the string, so `userId2`
+    /// and `Vec3` are fine but `3dModel` is not, this is the rule most programming languages
+    /// use for identifiers
+    #[default]
+    NotLeading,
+    /// Digits are allowed anywhere except as the first character of a word, so `api_v2` is fine
+    /// but `api_2` is not
+    NotWordStart,
+    /// Digits are allowed anywhere
+    Anywhere,
+}
+
+/// The programming languages [`ToIdentifier`] can create identifiers for
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    /// Rust, reserved words become raw identifiers such as `r#type`
+    Rust,
+    /// Python 3, reserved words get a trailing `_` such as `class_`
+    Python,
+    /// JavaScript in strict mode, reserved words get a trailing `_` such as `new_`
+    JavaScript,
+}
+
+impl Language {
+    /// Returns the case the language uses for variables and fields, `snake_case` for Rust and
+    /// Python and `camelCase` for JavaScript
+    #[must_use]
+    pub fn default_case(self) -> Case {
+        match self {
+            Language::Rust | Language::Python => Case::Snake,
+            Language::JavaScript => Case::Camel,
+        }
+    }
+}
+
+/// Declares methods for turning any string, such as a field name of a schema, into a valid
+/// identifier of a programming language
+pub trait ToIdentifier {
+    /// Creates a valid identifier of `language` in the case the language uses for variables and
+    /// fields, see [`Language::default_case`]
+    ///
+    /// # Note to Implementors
+    ///
+    /// The rules to create the identifier are:
+    /// - The string is converted to the case with [`ToCases::convert_case`], which already
+    ///   drops every character that is not a letter or a digit
+    /// - Any character left that is not a letter, an ASCII digit or `_` becomes `_`, like the
+    ///   separator of `kebab-case`
+    /// - An identifier that starts with a digit gets an `_` in front of it, an empty one
+    ///   becomes `_`, or `__` for Rust where a lone `_` is not an identifier
+    /// - Reserved words are escaped, Rust uses raw identifiers such as `r#type` and appends an
+    ///   `_` to `self`, `Self`, `super` and `crate` which cannot be raw, Python and JavaScript
+    ///   append an `_` like `class_`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!("r#type", "type".to_identifier(Language::Rust));
+    ///
+    /// assert_eq!("_3d_model", "3d model".to_identifier(Language::Python));
+    ///
+    /// assert_eq!("contentType", "content-type".to_identifier(Language::JavaScript));
+    ///```
+    fn to_identifier(&self, language: Language) -> String {
+        self.to_identifier_with(language, language.default_case())
+    }
+
+    /// Creates a valid identifier of `language` in the given `case`, useful for names of types
+    /// which use `PascalCase`, see [`ToIdentifier::to_identifier`] for the rules
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!("UserAccount", "user_account".to_identifier_with(Language::Rust, Case::Pascal));
+    ///
+    /// assert_eq!("Self_", "self".to_identifier_with(Language::Rust, Case::Pascal));
+    ///```
+    fn to_identifier_with(&self, language: Language, case: Case) -> String;
+
+    /// Creates a valid Rust identifier in `snake_case`, see [`ToIdentifier::to_identifier`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!("r#match", "match".to_rust_ident());
+    ///
+    /// assert_eq!("_3d_model", "3d_model".to_rust_ident());
+    ///```
+    fn to_rust_ident(&self) -> String {
+        self.to_identifier(Language::Rust)
+    }
+
+    /// Creates a valid Python identifier in `snake_case`, see [`ToIdentifier::to_identifier`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!("class_", "class".to_python_ident());
+    ///
+    /// assert_eq!("user_id", "userId".to_python_ident());
+    ///```
+    fn to_python_ident(&self) -> String {
+        self.to_identifier(Language::Python)
+    }
+
+    /// Creates a valid JavaScript identifier in `camelCase`, see [`ToIdentifier::to_identifier`]
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!("new_", "new".to_js_ident());
+    ///
+    /// assert_eq!("_2faEnabled", "2fa-enabled".to_js_ident());
+    ///```
+    fn to_js_ident(&self) -> String {
+        self.to_identifier(Language::JavaScript)
+    }
+}
+
+/// Declares methods for inflecting English words, such as deriving the name of a database
+/// table or a REST route from the name of a struct
+///
+/// Only the last word of the string is inflected, so `"UserAccount"` becomes `"UserAccounts"`
+/// and `"user account"` becomes `"user accounts"`, irregular words such as `person` and
+/// uncountable words such as `sheep` are known and the case of the word is kept
+///
+/// Words are split like the case conversions split them, so `"HTTPStatus"` becomes
+/// `"HTTPStatuses"`, and short all uppercase words are taken as acronyms, so `"ID"` becomes
+/// `"IDs"` while `"USER"` becomes `"USERS"`
+pub trait Inflect {
+    /// Creates a new string with its last word in plural
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Inflect;
+    ///
+    /// assert_eq!("categories", "category".pluralize());
+    ///
+    /// assert_eq!("People", "Person".pluralize());
+    ///
+    /// assert_eq!("sheep", "sheep".pluralize());
+    ///```
+    fn pluralize(&self) -> String;
+
+    /// Creates a new string with its last word in singular
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Inflect;
+    ///
+    /// assert_eq!("category", "categories".singularize());
+    ///
+    /// assert_eq!("child", "children".singularize());
+    ///```
+    fn singularize(&self) -> String;
+
+    /// Creates a new string with the English ordinal suffix appended to the number the string
+    /// ends with, the string is copied as it is if it does not end with a number
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Inflect;
+    ///
+    /// assert_eq!("1st", "1".ordinalize());
+    ///
+    /// assert_eq!("22nd", "22".ordinalize());
+    ///
+    /// assert_eq!("the 13th", "the 13".ordinalize());
+    ///```
+    fn ordinalize(&self) -> String;
+
+    /// Creates the name of a database table for the string, which is the string in `snake_case`
+    /// with its last word in plural
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Inflect;
+    ///
+    /// assert_eq!("user_accounts", "UserAccount".tableize());
+    ///
+    /// assert_eq!("people", "Person".tableize());
+    ///```
+    fn tableize(&self) -> String;
+}
+
+/// Declares methods for creating URL slugs, such as `creme-brulee` for `Crème Brûlée`
+pub trait ToSlug {
+    /// Creates a new string that only contains lowercase ASCII letters, digits and single `-`
+    /// between words
+    ///
+    /// # Note to Implementors
+    ///
+    /// The rules to create the slug are:
+    /// - Accented Latin, Greek and Cyrillic letters are transliterated to ASCII, `ü` becomes
+    ///   `u`, `ß` becomes `ss` and `ж` becomes `zh`
+    /// - Apostrophes are removed so `don't` becomes `dont`, every other character that is not a
+    ///   letter or a digit separates words
+    /// - Letters that cannot be transliterated, such as CJK characters, are removed
+    /// - Words are joined like [`ToCases::to_kebab_case`] does, so runs of separators collapse
+    ///   and there is no `-` at the start or the end
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::ToSlug;
+    ///
+    /// assert_eq!("creme-brulee", "Crème Brûlée".to_slug());
+    ///
+    /// assert_eq!("dont-panic-42", "  Don't   panic!! -- 42 ".to_slug());
+    ///
+    /// assert_eq!("privet-mir", "Привет, мир".to_slug());
+    ///```
+    fn to_slug(&self) -> String;
+
+    /// Creates a slug like [`ToSlug::to_slug`] that is at most `max_length` bytes long, the
+    /// slug is cut at the end of the last word that fits, a first word longer than
+    /// `max_length` is cut in the middle
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::ToSlug;
+    ///
+    /// assert_eq!("creme-brulee", "Crème Brûlée au chocolat".to_slug_with_max_length(14));
+    ///```
+    fn to_slug_with_max_length(&self, max_length: usize) -> String {
+        let mut slug = self.to_slug();
+
+        if slug.len() > max_length {
+            let end = slug[..=max_length]
+                .rfind('-')
+                .filter(|end| *end > 0)
+                .unwrap_or(max_length);
+            slug.truncate(end);
+        }
+        slug
+    }
+}
+
+/// A rule of a [`Case`] that a string can break, see [`IsCases::validate_case`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CaseRule {
+    /// The string is empty
+    Empty,
+    /// There is a whitespace where it is not the separator of the case
+    Whitespace,
+    /// There is a character that is not a letter, a digit or the separator of the case
+    IllegalCharacter(char),
+    /// A word starts with an uppercase character but it must start with a lowercase one, like
+    /// the first word of `camelCase`
+    LeadingUppercase,
+    /// A word starts with a lowercase character but it must start with an uppercase one, like
+    /// every word of `PascalCase`
+    LeadingLowercase,
+    /// There is an uppercase character inside a word that must be lowercase
+    UnexpectedUppercase,
+    /// There is a lowercase character inside a word that must be uppercase
+    UnexpectedLowercase,
+    /// The string is all lowercase, so it has no camel humps
+    AllLowercase,
+    /// There is a digit where the [`DigitRule`] does not allow it
+    MisplacedDigit,
+    /// There are two separators in a row or the string starts or ends with a separator
+    EmptyWord,
+}
+
+impl fmt::Display for CaseRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CaseRule::Empty => f.write_str("the string is empty"),
+            CaseRule::Whitespace => f.write_str("whitespace is not allowed"),
+            CaseRule::IllegalCharacter(character) => {
+                write!(f, "the character `{character}` is not allowed")
+            }
+            CaseRule::LeadingUppercase => {
+                f.write_str("the word must start with a lowercase character")
+            }
+            CaseRule::LeadingLowercase => {
+                f.write_str("the word must start with an uppercase character")
+            }
+            CaseRule::UnexpectedUppercase => {
+                f.write_str("uppercase characters are not allowed here")
+            }
+            CaseRule::UnexpectedLowercase => {
+                f.write_str("lowercase characters are not allowed here")
+            }
+            CaseRule::AllLowercase => f.write_str("the string must not be all lowercase"),
+            CaseRule::MisplacedDigit => f.write_str("a digit is not allowed here"),
+            CaseRule::EmptyWord => f.write_str("separators must be between two words"),
+        }
+    }
+}
+
+/// The error returned by [`IsCases::validate_case`], it says which rule of the case was broken,
+/// where, and how the string looks when converted to the case
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CaseViolation {
+    /// The case the string was checked against
+    pub case: Case,
+    /// The rule the string breaks
+    pub rule: CaseRule,
+    /// The byte offset where the rule is broken
+    pub byte_offset: usize,
+    /// The character offset where the rule is broken
+    pub char_offset: usize,
+    /// The string converted to `case` with [`ToCases::convert_case`]
+    pub suggestion: String,
+}
+
+impl fmt::Display for CaseViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "not {}, {} at character {}, did you mean `{}`?",
+            self.case, self.rule, self.char_offset, self.suggestion
+        )
+    }
+}
+
+impl Error for CaseViolation {}
+
+pub trait IsCases {
+    /// Check if `String` is of `camelCase` form
+    ///
+    /// # Note to Implementors
+    ///
+    /// The rules for a string to be `camelCase` are:
+    /// - The `String` must not be `lower_case`
+    /// - The first character must not be uppercase
+    /// - There must not be whitespaces
+    /// - All characters must be `alphabetic` or digits, digits follow [`DigitRule::NotLeading`]
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::IsCases;
+    /// let case1 = String::from("thisIsCamelCase");
+    ///
+    /// assert!(case1.is_camel_case());
+    /// let case2 = String::from("ThisisNotCamelCase");
+    ///
+    /// assert!(!case2.is_camel_case());
+    /// let case3 = String::from("this isnot_camel_case");
+    ///
+    /// assert!(!case3.is_camel_case());
+    /// let case4 = String::from("this#isnotCamelcase");
+    ///
+    /// assert!(!case4.is_camel_case());
+    /// let case5 = String::from("thisIs%&notcamelcase");
+    ///
+    /// assert!(!case5.is_camel_case());
+    /// let case6 = String::from("thisisnotcamelcase");
+    ///
+    /// assert!(!case6.is_camel_case());
+    ///```
+    fn is_camel_case(&self) -> bool;
+
+    /// Check if `String` is of a `PascalCase` form
+    ///
+    /// # Note to Implementors
+    ///
+    /// The rules for a string to be `PascalCase` are:
+    /// - The `String` must not be `lower_case`
+    /// - The first character must be uppercase
+    /// - There must not be whitespaces
+    /// - All characters must be `alphabetic` or digits, digits follow [`DigitRule::NotLeading`]
+    ///
+    /// # Examples
+    ///```
+    /// use crate::strings::methods::IsCases;
+    /// let case1 = String::from("ThisIsPascalCase");
+    ///
+    /// assert!(case1.is_pascal_case());
+    ///
+    /// let case2 = String::from("thisisNotPascalCase");
+    ///
+    /// assert!(!case2.is_pascal_case());
+    ///
+    /// let case3 = String::from("this isnot_pascal_case");
+    ///
+    /// assert!(!case3.is_pascal_case());
+    ///
+    /// let case4 = String::from("this#isnotPascalcase");
+    ///
+    /// assert!(!case4.is_pascal_case());
+    ///
+    /// let case5 = String::from("thisIs%&notpascalcase");
+    /// assert!(!case5.is_pascal_case());
+    ///
+    /// let case6 = String::from("thisisnotpascalcase");
+    /// assert!(!case6.is_pascal_case());
+    ///````
+    fn is_pascal_case(&self) -> bool;
+
+    /// Check if `String` is of `snake_case` form
+    ///
+    /// # Note to Implementors
+    ///
+    /// The rules for a string to be `snake_case` are:
+    /// - Words are separated by a single `_`, the string must not start or end with `_`
+    /// - There must not be uppercase characters
+    /// - All other characters must be `alphabetic` or digits, digits follow
+    ///   [`DigitRule::NotLeading`]
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::IsCases;
+    ///
+    /// assert!(String::from("user_id2").is_snake_case());
+    ///
+    /// assert!(!String::from("user__id").is_snake_case());
+    ///
+    /// assert!(!String::from("User_id").is_snake_case());
+    ///```
+    fn is_snake_case(&self) -> bool;
+
+    /// Check if `String` is of `kebab-case` form
+    ///
+    /// # Note to Implementors
+    ///
+    /// The rules are the same as [`IsCases::is_snake_case`] with `-` as the separator
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::IsCases;
+    ///
+    /// assert!(String::from("dry-run").is_kebab_case());
+    ///
+    /// assert!(!String::from("dry_run").is_kebab_case());
+    ///```
+    fn is_kebab_case(&self) -> bool;
+
+    /// Check if `String` is of `SCREAMING_SNAKE_CASE` form
+    ///
+    /// # Note to Implementors
+    ///
+    /// The rules are the same as [`IsCases::is_snake_case`] but there must not be lowercase
+    /// characters instead
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::IsCases;
+    ///
+    /// assert!(String::from("MAX_RETRIES_3").is_screaming_snake_case());
+    ///
+    /// assert!(!String::from("MAX_retries").is_screaming_snake_case());
+    ///```
+    fn is_screaming_snake_case(&self) -> bool;
+
+    /// Check if `String` is of `Train-Case` form
+    ///
+    /// # Note to Implementors
+    ///
+    /// The rules for a string to be `Train-Case` are:
+    /// - Words are separated by a single `-`, the string must not start or end with `-`
+    /// - The first character of every word must be uppercase, the rest must be lowercase
+    /// - All characters must be `alphabetic` or digits, digits follow [`DigitRule::NotLeading`]
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::IsCases;
+    ///
+    /// assert!(String::from("Content-Type").is_train_case());
+    ///
+    /// assert!(!String::from("Content-type").is_train_case());
+    ///```
+    fn is_train_case(&self) -> bool;
+
+    /// Check if `String` is in the given [`Case`], with `digits` deciding where digits may
+    /// appear
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::*;
+    ///
+    /// let case1 = String::from("vec3");
+    ///
+    /// assert!(case1.is_case(Case::Snake, DigitRule::NotLeading));
+    ///
+    /// assert!(!case1.is_case(Case::Snake, DigitRule::Forbidden));
+    ///
+    /// assert!(String::from("3d_model").is_case(Case::Snake, DigitRule::Anywhere));
+    ///```
+    fn is_case(&self, case: Case, digits: DigitRule) -> bool;
+
+    /// Checks if `String` is in the given [`Case`] like [`IsCases::is_case`] does with the
+    /// default [`DigitRule`], but explains why it is not
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CaseViolation`] with the first rule the string breaks, where it breaks it and
+    /// the string converted to `case` as a suggestion
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert!(String::from("userId").validate_case(Case::Camel).is_ok());
+    ///
+    /// let violation = String::from("user Id").validate_case(Case::Camel).unwrap_err();
+    ///
+    /// assert_eq!(CaseRule::Whitespace, violation.rule);
+    ///
+    /// assert_eq!(4, violation.char_offset);
+    ///
+    /// assert_eq!("userId", violation.suggestion);
+    ///```
+    fn validate_case(&self, case: Case) -> Result<(), CaseViolation>;
+
+    /// Detects which [`Case`] the string is written in
+    ///
+    /// # Note to Implementors
+    ///
+    /// A string is in a case if [`IsCases::is_case`] returns `true` for it with the default
+    /// [`DigitRule`], some strings such as `"name"` are valid in more than one case, in that
+    /// situation the first matching case of [`Case::ALL`] is returned
+    ///
+    /// Returns `None` if the string is empty or is not written in any case
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!(Some(Case::Kebab), String::from("user-account").detect_case());
+    ///
+    /// assert_eq!(Some(Case::Camel), String::from("userAccount").detect_case());
+    ///
+    /// assert_eq!(None, String::from("user Account").detect_case());
+    ///```
+    fn detect_case(&self) -> Option<Case>;
+}
+
+/// Declares methods for obtaining part of a string at a certain position
+///
+/// Positions count user-perceived characters instead of bytes or `char`s, so an emoji made of
+/// several code points such as `👩‍💻` or a letter followed by a combining accent is a single
+/// character that is never cut in half, and none of the methods panic
+pub trait SelectNth {
+    /// Obtains the first character of a string, or an empty string if there are no characters
+    ///
+    /// # Examples
+    ///
+    ///
+    ///```
+    /// use crate::strings::methods::SelectNth;
+    ///
+    /// let case1 = String::from("obtain the first character");
+    ///
+    /// assert_eq!("o", case1.first());
+    ///
+    /// assert_eq!("H", "Helo world".first());
+    ///
+    /// assert_eq!("e\u{301}", "e\u{301}lan".first());
+    ///```
+    ///
+    fn first(&self) -> &str;
+
+    /// Obtains the character at `index`, or `None` if the string is shorter than that
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::SelectNth;
+    ///
+    /// assert_eq!(Some("🇪🇸"), "hola 🇪🇸".nth(5));
+    ///
+    /// assert_eq!(None, "hola".nth(4));
+    ///```
+    fn nth(&self, index: usize) -> Option<&str>;
+
+    /// Obtains the last character of a string, or `None` if there are no characters
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::SelectNth;
+    ///
+    /// assert_eq!(Some("👍🏽"), "nice 👍🏽".last());
+    ///
+    /// assert_eq!(None, "".last());
+    ///```
+    fn last(&self) -> Option<&str>;
+
+    /// Obtains the first `count` characters, or the whole string if it is shorter than that
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::SelectNth;
+    ///
+    /// assert_eq!("Jo", "José".first_n(2));
+    ///
+    /// assert_eq!("José", "José".first_n(10));
+    ///```
+    fn first_n(&self, count: usize) -> &str;
+
+    /// Obtains the last `count` characters, or the whole string if it is shorter than that
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::SelectNth;
+    ///
+    /// assert_eq!("sé", "José".last_n(2));
+    ///```
+    fn last_n(&self, count: usize) -> &str;
+
+    /// Obtains the characters in `range`, or `None` if the range is out of bounds or its start
+    /// is after its end
+    ///
+    /// # Examples
+    ///
+    ///```
+    /// use crate::strings::methods::SelectNth;
+    ///
+    /// let case1 = "Zoë 👩‍👩‍👧 Ünal";
+    ///
+    /// assert_eq!(Some("👩‍👩‍👧"), case1.char_slice(4..5));
+    ///
+    /// assert_eq!(Some("Ünal"), case1.char_slice(6..));
+    ///
+    /// assert_eq!(None, case1.char_slice(6..20));
+    ///```
+    fn char_slice(&self, range: impl RangeBounds<usize>) -> Option<&str>;
+}
+
+/// Declares methods for laying out strings in a terminal, where East Asian wide characters
+/// and most emoji take two columns and combining marks take none
+///
+/// Every width is measured in terminal columns and strings are only ever cut between
+/// user-perceived characters, like [`SelectNth`] does
+pub trait DisplayWidth {
+    /// Returns how many terminal columns the string takes
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::DisplayWidth;
+    ///
+    /// assert_eq!(5, "hello".display_width());
+    ///
+    /// assert_eq!(6, "日本語".display_width());
+    ///
+    /// assert_eq!(4, "e\u{301}lan".display_width());
+    ///```
+    fn display_width(&self) -> usize;
+
+    /// Creates a new string that takes at most `width` columns, when the string is wider it is
+    /// cut and `ellipsis` is appended, the ellipsis counts towards `width`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::DisplayWidth;
+    ///
+    /// assert_eq!("hello", "hello".truncate_to_width(5, "…"));
+    ///
+    /// assert_eq!("hel…", "hello world".truncate_to_width(4, "…"));
+    ///
+    /// assert_eq!("日本…", "日本語のテキスト".truncate_to_width(6, "…"));
+    ///```
+    fn truncate_to_width(&self, width: usize, ellipsis: &str) -> String;
+
+    /// Creates a new string padded with spaces on the left so it takes `width` columns, the
+    /// string is copied as it is if it is already that wide
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::DisplayWidth;
+    ///
+    /// assert_eq!("   42", "42".pad_left_to_width(5));
+    ///
+    /// assert_eq!(" 山田", "山田".pad_left_to_width(5));
+    ///```
+    fn pad_left_to_width(&self, width: usize) -> String;
+
+    /// Creates a new string padded with spaces on the right so it takes `width` columns, the
+    /// string is copied as it is if it is already that wide
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::DisplayWidth;
+    ///
+    /// assert_eq!("name |", format!("{}|", "name".pad_right_to_width(5)));
+    ///
+    /// assert_eq!("山田 |", format!("{}|", "山田".pad_right_to_width(5)));
+    ///```
+    fn pad_right_to_width(&self, width: usize) -> String;
+
+    /// Creates a new string centered in `width` columns using `fill` on both sides, when the
+    /// padding cannot be split evenly the extra column goes to the right, and when `fill` is a
+    /// wide character the columns it cannot fill are filled with spaces
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::DisplayWidth;
+    ///
+    /// assert_eq!("--abc---", "abc".center(8, '-'));
+    ///
+    /// assert_eq!("*日本*", "日本".center(6, '*'));
+    ///```
+    fn center(&self, width: usize, fill: char) -> String;
+}
+
+/// Options for [`Wrap::wrap_with`] and [`Wrap::fill_with`]
+///
+/// # Examples
+///
+/// ```
+/// use crate::strings::methods::*;
+///
+/// let options = WrapOptions::new(12).initial_indent("- ").subsequent_indent("  ");
+///
+/// assert_eq!(
+///     vec!["- first item", "  of the", "  list"],
+///     "first item of the list".wrap_with(&options)
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WrapOptions {
+    pub(crate) width: usize,
+    pub(crate) initial_indent: String,
+    pub(crate) subsequent_indent: String,
+}
+
+impl WrapOptions {
+    /// Creates options to wrap lines at `width` columns without any indent
+    #[must_use]
+    pub fn new(width: usize) -> Self {
+        Self {
+            width,
+            initial_indent: String::new(),
+            subsequent_indent: String::new(),
+        }
+    }
+
+    /// Sets the text put at the start of the first line of every paragraph, its width counts
+    /// towards the width of the line
+    #[must_use]
+    pub fn initial_indent(mut self, indent: impl Into<String>) -> Self {
+        self.initial_indent = indent.into();
+        self
+    }
+
+    /// Sets the text put at the start of every line of a paragraph but the first one, its width
+    /// counts towards the width of the line
+    #[must_use]
+    pub fn subsequent_indent(mut self, indent: impl Into<String>) -> Self {
+        self.subsequent_indent = indent.into();
+        self
+    }
+}
+
+/// Declares methods for wrapping text into lines of a maximum width, such as help messages
+/// or previews of commit messages
+pub trait Wrap {
+    /// Splits the string into lines that take at most `width` columns
+    ///
+    /// # Note to Implementors
+    ///
+    /// The rules to wrap the string are:
+    /// - Lines are broken at whitespaces and the whitespaces between words are collapsed into a
+    ///   single space
+    /// - A word wider than a whole line is broken between its characters
+    /// - Blank lines separate paragraphs, every paragraph is wrapped on its own and every blank
+    ///   line is kept as an empty line, the line breaks inside a paragraph are not kept
+    /// - Widths are measured in terminal columns like [`DisplayWidth::display_width`] does
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Wrap;
+    ///
+    /// assert_eq!(vec!["the quick", "brown fox"], "the quick brown fox".wrap(10));
+    ///
+    /// assert_eq!(vec!["abcd", "efgh", "ij"], "abcdefghij".wrap(4));
+    ///```
+    fn wrap(&self, width: usize) -> Vec<String> {
+        self.wrap_with(&WrapOptions::new(width))
+    }
+
+    /// Splits the string into lines following `options`, see [`Wrap::wrap`] for the rules
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// let options = WrapOptions::new(10).subsequent_indent("  ");
+    ///
+    /// let case1 = "the quick brown fox";
+    ///
+    /// assert_eq!(vec!["the quick", "  brown", "  fox"], case1.wrap_with(&options));
+    ///```
+    fn wrap_with(&self, options: &WrapOptions) -> Vec<String>;
+
+    /// Wraps the string like [`Wrap::wrap`] and joins the lines with `\n`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Wrap;
+    ///
+    /// assert_eq!("the quick\nbrown fox\n\njumps", "the quick brown fox\n\njumps".fill(10));
+    ///```
+    fn fill(&self, width: usize) -> String {
+        self.wrap(width).join("\n")
+    }
+
+    /// Wraps the string like [`Wrap::wrap_with`] and joins the lines with `\n`
+    fn fill_with(&self, options: &WrapOptions) -> String {
+        self.wrap_with(options).join("\n")
+    }
+}
+
+/// Declares methods for indenting and dedenting multi-line strings, such as templates of
+/// source code or YAML
+///
+/// Like Python's `textwrap`, lines that only contain whitespaces are never indented and the
+/// line breaks, `\n` or `\r\n`, are kept as they are
+pub trait Indent {
+    /// Creates a new string with `prefix` added at the start of every line that is not blank
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Indent;
+    ///
+    /// assert_eq!("    a: 1\n\n    b: 2\n", "a: 1\n\nb: 2\n".indent("    "));
+    ///```
+    fn indent(&self, prefix: &str) -> String {
+        self.indent_with(|_| prefix)
+    }
+
+    /// Creates a new string with the prefix returned by `prefix` added at the start of every
+    /// line that is not blank, `prefix` receives the number of the line starting from 0, blank
+    /// lines are counted too but `prefix` is not called for them
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Indent;
+    ///
+    /// let numbered = "fn main() {\n}".indent_with(|line| format!("{} | ", line + 1));
+    ///
+    /// assert_eq!("1 | fn main() {\n2 | }", numbered);
+    ///```
+    fn indent_with<P: AsRef<str>>(&self, prefix: impl FnMut(usize) -> P) -> String;
+
+    /// Creates a new string with the longest whitespace prefix that all the lines that are not
+    /// blank have in common removed, blank lines become empty lines
+    ///
+    /// # Note to Implementors
+    ///
+    /// Only spaces and tabs count as indentation and they are not considered equal, so a line
+    /// indented with a tab and a line indented with spaces have nothing in common
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Indent;
+    ///
+    /// assert_eq!("a:\n  b: 1\n\nc: 2\n", "    a:\n      b: 1\n  \n    c: 2\n".dedent());
+    ///```
+    fn dedent(&self) -> String;
+}
+
+/// The maximum number of suggestions returned by [`Similarity::closest_match`]
+const MAX_SUGGESTIONS: usize = 3;
+
+/// Declares methods that measure how close two strings are, to suggest the right spelling of
+/// a mistyped key or command
+///
+/// All the distances are counted in `char`s and are case-sensitive, normalize both strings
+/// first, for example with `to_snake_case`, to ignore the case and the separators
+pub trait Similarity {
+    /// Returns the number of characters to insert, delete or substitute to turn this string
+    /// into `other`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Similarity;
+    ///
+    /// assert_eq!(3, "kitten".levenshtein("sitting"));
+    ///```
+    #[must_use]
+    fn levenshtein(&self, other: &str) -> usize;
+
+    /// Returns the Levenshtein distance where swapping two adjacent characters also counts as
+    /// a single edit
+    ///
+    /// # Note to Implementors
+    ///
+    /// This is the optimal string alignment distance, a substring can not be edited again after
+    /// being transposed, so `"ca"` and `"abc"` are 3 edits apart instead of 2
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Similarity;
+    ///
+    /// assert_eq!(1, "teh".damerau_levenshtein("the"));
+    ///```
+    #[must_use]
+    fn damerau_levenshtein(&self, other: &str) -> usize;
+
+    /// Returns the Jaro-Winkler similarity, from 0.0 for strings with nothing in common to
+    /// 1.0 for equal strings, common leading characters weigh more
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Similarity;
+    ///
+    /// assert!("martha".jaro_winkler("marhta") > 0.96);
+    ///```
+    #[must_use]
+    fn jaro_winkler(&self, other: &str) -> f64;
+
+    /// Returns the Damerau-Levenshtein distance as a similarity from 0.0 to 1.0, relative to
+    /// the length of the longest string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Similarity;
+    ///
+    /// assert_eq!(0.75, "node".normalized_similarity("mode"));
+    ///```
+    #[must_use]
+    fn normalized_similarity(&self, other: &str) -> f64;
+
+    /// Returns up to 3 `candidates` whose normalized similarity to this string is at least
+    /// `threshold`, the most similar first, candidates with the same score keep their order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Similarity;
+    ///
+    /// let keys = ["color", "columns", "border"];
+    ///
+    /// assert_eq!(vec!["color"], "colour".closest_match(&keys, 0.7));
+    /// assert!("width".closest_match(&keys, 0.7).is_empty());
+    ///```
+    #[must_use]
+    fn closest_match<'c, C>(
+        &self,
+        candidates: impl IntoIterator<Item = &'c C>,
+        threshold: f64,
+    ) -> Vec<&'c str>
+    where
+        C: AsRef<str> + ?Sized + 'c;
+}
+
+/// Declares comparisons in natural order, where the numbers inside the strings are compared
+/// by their value, so `"file2"` comes before `"file10"` and `"v1.9"` before `"v1.10"`
+///
+/// The comparisons fit `sort_by` directly, e.g. `files.sort_by(|a, b| a.natural_cmp(b))`
+///
+/// # Note to Implementors
+///
+/// Numbers are runs of ASCII digits of any length, they never overflow. Numbers that only
+/// differ by their leading zeros are equal unless everything else is equal too, then the
+/// number with fewer leading zeros comes first, so `"a1"` comes before `"a01"`
+pub trait NaturalCmp {
+    /// Compares this string with `other` in natural order, letters are compared by their
+    /// code point, so uppercase letters come before lowercase ones
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::NaturalCmp;
+    ///
+    /// let mut files = vec!["file10.txt", "file2.txt", "file1.txt"];
+    /// files.sort_by(|a, b| a.natural_cmp(b));
+    ///
+    /// assert_eq!(vec!["file1.txt", "file2.txt", "file10.txt"], files);
+    ///```
+    #[must_use]
+    fn natural_cmp(&self, other: &str) -> Ordering;
+
+    /// Compares this string with `other` in natural order, ignoring the case of the letters
+    /// by comparing their lowercase forms
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::NaturalCmp;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Ordering::Less, "Report2".natural_cmp_ignore_case("report10"));
+    ///```
+    #[must_use]
+    fn natural_cmp_ignore_case(&self, other: &str) -> Ordering;
+
+    /// Compares this string with `other` in natural order after the Unicode case folding of
+    /// both strings, which also matches forms such as `ß` and `ss` or `ς` and `σ`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::NaturalCmp;
+    /// use std::cmp::Ordering;
+    ///
+    /// assert_eq!(Ordering::Equal, "STRASSE 5".natural_cmp_folded("straße 5"));
+    ///```
+    #[must_use]
+    fn natural_cmp_folded(&self, other: &str) -> Ordering;
+}
+
+/// The result of [`FuzzySearch::fuzzy_match`], higher scores are better matches
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// How well the pattern matches, only comparable between matches of the same pattern
+    pub score: i32,
+    /// The character indices, not byte offsets, of the matched characters in increasing order
+    pub positions: Vec<usize>,
+}
+
+/// Declares fuzzy matching in the style of a command palette, where the characters of a
+/// pattern must appear in order in the string but not next to each other
+pub trait FuzzySearch {
+    /// Returns the best way the characters of `pattern` appear in order in this string, with
+    /// its score and the positions to highlight, or `None` if they do not all appear
+    ///
+    /// # Note to Implementors
+    ///
+    /// The rules to score a match are:
+    /// - The search ignores the case unless `pattern` contains an uppercase character, characters
+    ///   are compared by their case folding so `İ` matches `i` and `ς` matches `σ`
+    /// - Every matched character is worth points, more when it is right after another match,
+    ///   then it also keeps the bonus of the first match of the run
+    /// - Matches at the start of a word, as split by [`ToCases`], earn a bonus: after a
+    ///   separator such as `_`, `-`, `/` or a space, or at a camel hump
+    /// - The first character of the pattern earns twice the bonus of its position
+    /// - Characters skipped between two matches cost points, leading and trailing ones do not
+    /// - An empty pattern matches everything with a score of 0
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::FuzzySearch;
+    ///
+    /// let found = "src/strings/methods.rs".fuzzy_match("smr").unwrap();
+    ///
+    /// assert_eq!(vec![4, 12, 20], found.positions);
+    ///
+    /// let word_starts = "open_file".fuzzy_match("of").unwrap();
+    /// let inside_word = "profile".fuzzy_match("of").unwrap();
+    ///
+    /// assert!(word_starts.score > inside_word.score);
+    /// assert_eq!(None, "openFile".fuzzy_match("fo"));
+    ///```
+    #[must_use]
+    fn fuzzy_match(&self, pattern: &str) -> Option<FuzzyMatch>;
+}
+
+/// Declares case-insensitive comparisons with the full case folding of Unicode and case
+/// conversions that follow the rules of a [`Locale`]
+///
+/// Case folding is what Unicode recommends to compare strings without their case, it is close
+/// to lowercasing but also matches forms such as `ß` and `ss` or `ς` and `σ`
+pub trait LocaleCase {
+    /// Creates a new string with the full case folding of Unicode applied
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::LocaleCase;
+    ///
+    /// assert_eq!("strasse", "Straße".fold_case());
+    ///```
+    #[must_use]
+    fn fold_case(&self) -> String {
+        self.fold_case_in(Locale::Root)
+    }
+
+    /// Creates a new string with the full case folding of Unicode applied, following the
+    /// special folding of the dotted and dotless i in Turkish and Azerbaijani
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!("ıi", "Iİ".fold_case_in(Locale::Turkish));
+    ///```
+    #[must_use]
+    fn fold_case_in(&self, locale: Locale) -> String;
+
+    /// Checks if this string and `other` are equal once case folded
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::LocaleCase;
+    ///
+    /// assert!("MASSE".eq_ignore_case("Maße"));
+    /// assert!("ΣΊΣΥΦΟΣ".eq_ignore_case("σίσυφος"));
+    ///```
+    #[must_use]
+    fn eq_ignore_case(&self, other: &str) -> bool {
+        self.eq_ignore_case_in(other, Locale::Root)
+    }
+
+    /// Checks if this string and `other` are equal once case folded following the rules of
+    /// `locale`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert!("DİYARBAKIR".eq_ignore_case_in("diyarbakır", Locale::Turkish));
+    /// assert!(!"DİYARBAKIR".eq_ignore_case("diyarbakır"));
+    ///```
+    #[must_use]
+    fn eq_ignore_case_in(&self, other: &str, locale: Locale) -> bool;
+
+    /// Creates a new string lowercased following the rules of `locale`, `Σ` becomes the final
+    /// sigma `ς` at the end of a word in every locale
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!("ısparta", "ISPARTA".to_lowercase_in(Locale::Turkish));
+    /// assert_eq!("i̇̀", "Ì".to_lowercase_in(Locale::Lithuanian));
+    ///```
+    #[must_use]
+    fn to_lowercase_in(&self, locale: Locale) -> String;
+
+    /// Creates a new string uppercased following the rules of `locale`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::*;
+    ///
+    /// assert_eq!("İSTANBUL", "istanbul".to_uppercase_in(Locale::Turkish));
+    ///```
+    #[must_use]
+    fn to_uppercase_in(&self, locale: Locale) -> String;
+}
+
+/// What is wrong with an escaped string, see [`UnescapeError`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum UnescapeErrorKind {
+    /// The string ends in the middle of an escape sequence
+    TruncatedEscape,
+    /// A backslash is followed by a character that is not a known escape
+    UnknownEscape(char),
+    /// An escape sequence or a numeric character reference contains a character that is not a
+    /// digit of its base
+    InvalidDigit(char),
+    /// An escape sequence or a numeric character reference stands for a number that is not a
+    /// valid character, such as an unpaired surrogate, or a byte above `0xFF`
+    InvalidCodePoint(u32),
+    /// The bytes written with escape sequences do not form valid UTF-8
+    InvalidUtf8,
+    /// A character that must be escaped is not, such as a `"` inside a JSON string
+    UnescapedCharacter(char),
+    /// A named character reference of XML such as `&nope;` is not known, unknown references of
+    /// HTML are kept as text instead
+    UnknownEntity(String),
+    /// A character reference does not end with `;`
+    UnterminatedEntity,
+    /// A quote is never closed
+    UnterminatedQuote,
+}
+
+impl fmt::Display for UnescapeErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnescapeErrorKind::TruncatedEscape => f.write_str("the escape sequence is truncated"),
+            UnescapeErrorKind::UnknownEscape(character) => {
+                write!(f, "unknown escape sequence `\\{character}`")
+            }
+            UnescapeErrorKind::InvalidDigit(character) => write!(f, "invalid digit `{character}`"),
+            UnescapeErrorKind::InvalidCodePoint(code) => {
+                write!(f, "{code:#x} is not a valid character")
+            }
+            UnescapeErrorKind::InvalidUtf8 => f.write_str("the escaped bytes are not valid UTF-8"),
+            UnescapeErrorKind::UnescapedCharacter(character) => {
+                write!(f, "`{}` must be escaped", character.escape_debug())
+            }
+            UnescapeErrorKind::UnknownEntity(name) => write!(f, "unknown entity `&{name};`"),
+            UnescapeErrorKind::UnterminatedEntity => {
+                f.write_str("the character reference does not end with `;`")
+            }
+            UnescapeErrorKind::UnterminatedQuote => f.write_str("the quote is never closed"),
+        }
+    }
+}
+
+/// The error returned by the `unescape_*` methods of [`Escape`], it says what is wrong and
+/// where the offending escape sequence or character starts
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnescapeError {
+    /// What is wrong with the string
+    pub kind: UnescapeErrorKind,
+    /// The byte offset where the problem starts
+    pub byte_offset: usize,
+    /// The character offset where the problem starts
+    pub char_offset: usize,
+}
+
+impl fmt::Display for UnescapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at character {}", self.kind, self.char_offset)
+    }
+}
+
+impl Error for UnescapeError {}
+
+/// Declares methods to embed strings in JSON, C, HTML, XML and POSIX shell sources and to read
+/// them back
+///
+/// Every `escape_*` method has an `unescape_*` method that gives the original string back, the
+/// unescaping methods are strict and say where the first problem is
+pub trait Escape {
+    /// Creates a new string escaped to be the content of a JSON string, without the quotes
+    /// around it
+    ///
+    /// # Note to Implementors
+    ///
+    /// `"`, `\` and the control characters are escaped, and so are `U+2028` and `U+2029`
+    /// that older JavaScript engines do not accept in strings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Escape;
+    ///
+    /// assert_eq!(r#"say \"hi\"\n"#, "say \"hi\"\n".escape_json());
+    ///```
+    #[must_use]
+    fn escape_json(&self) -> String;
+
+    /// Reads the content of a JSON string, without the quotes around it
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UnescapeError`] for unknown escapes, unescaped `"` and control characters,
+    /// invalid `\u` escapes and unpaired surrogates
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Escape;
+    ///
+    /// assert_eq!(Ok(String::from("😀 é")), r"\ud83d\ude00 \u00e9".unescape_json());
+    /// assert_eq!(3, r"abc\q".unescape_json().unwrap_err().byte_offset);
+    ///```
+    fn unescape_json(&self) -> Result<String, UnescapeError>;
+
+    /// Creates a new string escaped to be the content of a C string literal, without the
+    /// quotes around it
+    ///
+    /// # Note to Implementors
+    ///
+    /// The control characters without a short escape such as `\n` become 3-digit octal
+    /// escapes, which never swallow the characters after them like `\x` escapes do
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Escape;
+    ///
+    /// assert_eq!(r#"tab\t\"q\"\0331"#, "tab\t\"q\"\u{1b}1".escape_c());
+    ///```
+    #[must_use]
+    fn escape_c(&self) -> String;
+
+    /// Reads the content of a C string literal, without the quotes around it, octal and `\x`
+    /// escapes are bytes that together must form valid UTF-8
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UnescapeError`] for unknown escapes, escapes without digits, bytes above
+    /// `0xFF`, invalid code points and bytes that do not form valid UTF-8
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Escape;
+    ///
+    /// assert_eq!(Ok(String::from("café\n")), r"caf\xc3\xa9\n".unescape_c());
+    ///```
+    fn unescape_c(&self) -> Result<String, UnescapeError>;
+
+    /// Creates a new string escaped to be the text or an attribute value of an HTML document
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Escape;
+    ///
+    /// assert_eq!("&lt;b&gt;Tom &amp; Jerry&#39;s", "<b>Tom & Jerry's".escape_html());
+    ///```
+    #[must_use]
+    fn escape_html(&self) -> String;
+
+    /// Replaces the character references of HTML text, every named reference of HTML 4 is
+    /// known, an `&` that does not start a known named reference ending with `;` is kept as it
+    /// is, like browsers do
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UnescapeError`] for numeric references that are not terminated or are not
+    /// valid characters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Escape;
+    ///
+    /// let unescaped = "&copy; 2024 & &lt;more&#x3E;".unescape_html();
+    ///
+    /// assert_eq!(Ok(String::from("© 2024 & <more>")), unescaped);
+    ///```
+    fn unescape_html(&self) -> Result<String, UnescapeError>;
+
+    /// Creates a new string escaped to be the text or an attribute value of an XML document
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Escape;
+    ///
+    /// assert_eq!("a &lt; b &amp;&amp; c &apos;d&apos;", "a < b && c 'd'".escape_xml());
+    ///```
+    #[must_use]
+    fn escape_xml(&self) -> String;
+
+    /// Replaces the predefined entities and the character references of XML text
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UnescapeError`] for an `&` that does not start a reference ending with
+    /// `;`, a `<`, unknown entities and numeric references that are not valid characters
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Escape;
+    ///
+    /// assert_eq!(Ok(String::from("a < b")), "a &lt; b".unescape_xml());
+    /// assert!("a & b".unescape_xml().is_err());
+    ///```
+    fn unescape_xml(&self) -> Result<String, UnescapeError>;
+
+    /// Creates a new string quoted to be a single word of a POSIX shell, with single quotes
+    /// unless the string is only made of characters that are never special to the shell
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Escape;
+    ///
+    /// assert_eq!("report.txt", "report.txt".shell_quote());
+    /// assert_eq!(r"'it'\''s here'", "it's here".shell_quote());
+    ///```
+    #[must_use]
+    fn shell_quote(&self) -> String;
+
+    /// Reads a single word of a POSIX shell, with its single quotes, double quotes and
+    /// backslashes
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UnescapeError`] for unterminated quotes, a trailing backslash and the
+    /// characters that would make the shell split or expand the word: unquoted whitespace,
+    /// operators such as `|` or `;`, globs, `$` and `` ` ``, a leading `~` and a leading `#`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::Escape;
+    ///
+    /// assert_eq!(Ok(String::from("it's here")), r#"'it'\''s '"here""#.unescape_shell());
+    /// assert!("rm -rf".unescape_shell().is_err());
+    ///```
+    fn unescape_shell(&self) -> Result<String, UnescapeError>;
+}
+
+/// Declares methods that split strings into words or fields without breaking what is quoted
+pub trait SplitQuoted {
+    /// Splits the string into words like a POSIX shell does, without expanding variables,
+    /// globs or anything else
+    ///
+    /// # Note to Implementors
+    ///
+    /// The rules of the splitting are:
+    /// - Words are separated by whitespace that is not quoted or escaped
+    /// - Single quotes keep everything between them as it is
+    /// - Between double quotes, a backslash only escapes `$`, `` ` ``, `"`, `\` and newlines
+    /// - Outside quotes, a backslash escapes any character
+    /// - A backslash before a newline joins the two lines
+    /// - Quotes inside a word are removed, so `--name="a b"` is the single word `--name=a b`
+    /// - An unquoted `#` at the start of a word comments out the rest of the line
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UnescapeError`] with [`UnescapeErrorKind::UnterminatedQuote`] and the
+    /// position of the opening quote when a quote is never closed, or with
+    /// [`UnescapeErrorKind::TruncatedEscape`] when the string ends with a backslash
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::SplitQuoted;
+    ///
+    /// let words = r#"grep -e "hello world" 'it''s' a\ b"#.split_shell_words().unwrap();
+    ///
+    /// assert_eq!(vec!["grep", "-e", "hello world", "its", "a b"], words);
+    /// assert!("echo 'oops".split_shell_words().is_err());
+    ///```
+    fn split_shell_words(&self) -> Result<Vec<String>, UnescapeError>;
+
+    /// Splits the string into the fields separated by `delimiter`, a delimiter between two
+    /// `quote` characters belongs to the field and `escape` makes the next character literal
+    ///
+    /// # Note to Implementors
+    ///
+    /// The quotes are removed from the fields. When `escape` is the same character as `quote`,
+    /// a doubled quote inside quotes is a literal quote, like in CSV. Like [`str::split`], an
+    /// empty string is a single empty field
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UnescapeError`] with [`UnescapeErrorKind::UnterminatedQuote`] and the
+    /// position of the opening quote when a quote is never closed, or with
+    /// [`UnescapeErrorKind::TruncatedEscape`] when the string ends with `escape`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::SplitQuoted;
+    ///
+    /// let filter = r#"name="Smith, J";tag=a\;b"#.split_quoted(';', '"', '\\').unwrap();
+    ///
+    /// assert_eq!(vec!["name=Smith, J", "tag=a;b"], filter);
+    ///
+    /// let row = r#"1,"say ""hi""",3"#.split_quoted(',', '"', '"').unwrap();
+    ///
+    /// assert_eq!(vec!["1", r#"say "hi""#, "3"], row);
+    ///```
+    fn split_quoted(
+        &self,
+        delimiter: char,
+        quote: char,
+        escape: char,
+    ) -> Result<Vec<String>, UnescapeError>;
+}
+
+/// Declares a method that joins words into a POSIX shell command line, it is implemented for
+/// slices and vectors of strings
+pub trait JoinShellWords {
+    /// Joins the words with spaces, each one quoted with [`Escape::shell_quote`] when needed,
+    /// so that [`SplitQuoted::split_shell_words`] gives the same words back
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::{JoinShellWords, SplitQuoted};
+    ///
+    /// let words = vec!["echo", "it's", "a b", ""];
+    /// let line = words.join_shell_words();
+    ///
+    /// assert_eq!(r"echo 'it'\''s' 'a b' ''", line);
+    /// assert_eq!(words, line.split_shell_words().unwrap());
+    ///```
+    #[must_use]
+    fn join_shell_words(&self) -> String;
+}
+
+/// The values of the placeholders of [`FormatNamed::format_named`], it is implemented for
+/// `HashMap` and `BTreeMap` with string keys and for closures that take the name of the
+/// placeholder, as long as their values implement `Display`
+///
+/// # Examples
+///
+/// ```
+/// use crate::strings::methods::*;
+/// use std::collections::HashMap;
+///
+/// let values = HashMap::from([("count", 3)]);
+///
+/// assert_eq!(Some(String::from("3")), values.lookup("count"));
+///
+/// let upper = |name: &str| Some(name.to_uppercase());
+///
+/// assert_eq!(Some(String::from("USER")), upper.lookup("user"));
+/// ```
+pub trait Lookup {
+    /// Returns the value of the placeholder `name`, or `None` if there is none
+    fn lookup(&self, name: &str) -> Option<String>;
+}
+
+impl<K, V, S> Lookup for HashMap<K, V, S>
+where
+    K: Borrow<str> + Hash + Eq,
+    V: fmt::Display,
+    S: BuildHasher,
+{
+    fn lookup(&self, name: &str) -> Option<String> {
+        self.get(name).map(ToString::to_string)
+    }
+}
+
+impl<K, V> Lookup for BTreeMap<K, V>
+where
+    K: Borrow<str> + Ord,
+    V: fmt::Display,
+{
+    fn lookup(&self, name: &str) -> Option<String> {
+        self.get(name).map(ToString::to_string)
+    }
+}
+
+impl<F, V> Lookup for F
+where
+    F: Fn(&str) -> Option<V>,
+    V: fmt::Display,
+{
+    fn lookup(&self, name: &str) -> Option<String> {
+        self(name).map(|value| value.to_string())
+    }
+}
+
+/// A placeholder without a value nor a fallback, see [`MissingKeysError`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingKey {
+    /// The name of the placeholder
+    pub name: String,
+    /// The byte offset of the `{` of the placeholder
+    pub byte_offset: usize,
+    /// The character offset of the `{` of the placeholder
+    pub char_offset: usize,
+}
+
+/// The error returned by [`FormatNamed::format_named_strict`], it lists every placeholder
+/// without a value nor a fallback in the order they appear
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingKeysError {
+    /// The placeholders without a value, never empty
+    pub missing: Vec<MissingKey>,
+}
+
+impl fmt::Display for MissingKeysError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("missing values for ")?;
+        for (index, key) in self.missing.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{}` at character {}", key.name, key.char_offset)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for MissingKeysError {}
+
+/// Declares methods that fill the `{name}` placeholders of templates that are only known at
+/// runtime, such as texts stored in a database, where `format!` can not be used
+///
+/// # Note to Implementors
+///
+/// The syntax of the templates is:
+/// - `{name}` is replaced by the value of `name`
+/// - `{name:-fallback}` is replaced by the value of `name`, or by `fallback` when there is none
+/// - `{{` and `}}` are a literal `{` and `}`
+/// - A `{` that is never closed, a lone `}` and `{}` are kept as they are
+pub trait FormatNamed {
+    /// Creates a new string with the placeholders replaced by their values, the placeholders
+    /// without a value nor a fallback are kept as they are
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::FormatNamed;
+    /// use std::collections::HashMap;
+    ///
+    /// let values = HashMap::from([("user", "Ada"), ("count", "3")]);
+    /// let template = "Hi {user}, you have {count} new {kind:-messages} {{inbox}}";
+    ///
+    /// assert_eq!("Hi Ada, you have 3 new messages {inbox}", template.format_named(&values));
+    ///```
+    #[must_use]
+    fn format_named(&self, values: &impl Lookup) -> String;
+
+    /// Creates a new string with the placeholders replaced by their values
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`MissingKeysError`] with the names and positions of all the placeholders
+    /// without a value nor a fallback
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::FormatNamed;
+    ///
+    /// let values = |name: &str| (name == "user").then_some("Ada");
+    ///
+    /// assert_eq!(Ok(String::from("Hi Ada")), "Hi {user}".format_named_strict(&values));
+    ///
+    /// let error = "Hi {user}, {greeting}".format_named_strict(&values).unwrap_err();
+    ///
+    /// assert_eq!("greeting", error.missing[0].name);
+    /// assert_eq!(11, error.missing[0].char_offset);
+    ///```
+    fn format_named_strict(&self, values: &impl Lookup) -> Result<String, MissingKeysError>;
+}
+
+/// What is wrong with a glob pattern, see [`ParseGlobError`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GlobErrorKind {
+    /// A `[` is never closed by a `]`
+    UnclosedClass,
+    /// The pattern ends with a backslash that escapes nothing
+    TrailingEscape,
+}
+
+impl fmt::Display for GlobErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GlobErrorKind::UnclosedClass => f.write_str("the `[` is never closed"),
+            GlobErrorKind::TrailingEscape => f.write_str("the pattern ends with a backslash"),
+        }
+    }
+}
+
+/// The error returned when compiling a [`GlobPattern`] from an invalid pattern
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseGlobError {
+    /// What is wrong with the pattern
+    pub kind: GlobErrorKind,
+    /// The byte offset where the problem starts
+    pub byte_offset: usize,
+    /// The character offset where the problem starts
+    pub char_offset: usize,
+}
+
+impl fmt::Display for ParseGlobError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid glob pattern, {} at character {}",
+            self.kind, self.char_offset
+        )
+    }
+}
+
+impl Error for ParseGlobError {}
+
+/// A compiled glob pattern, to match many strings without parsing the pattern again
+///
+/// The syntax of the patterns is:
+/// - `?` matches any character but `/`
+/// - `*` matches any run of characters without `/`, including an empty one
+/// - `[abc]`, `[a-z]` match one of the characters, `[!a-z]` or `[^a-z]` one character that is
+///   not, a `]` right after the `[` is a member, and they never match `/`
+/// - `**/` at the start of the pattern or after a `/` matches any number of whole segments, so
+///   `a/**/b` matches `a/b` and `a/x/y/b`
+/// - `**` at the end of the pattern after a `/` matches everything, `/` included, so `a/**`
+///   matches `a/x/y` but not `a`
+/// - A backslash makes the next character literal
+///
+/// # Examples
+///
+/// ```
+/// use crate::strings::methods::GlobPattern;
+///
+/// let secrets = GlobPattern::new("project/*/secrets/**").unwrap();
+///
+/// assert!(secrets.is_match("project/api/secrets/db/password"));
+/// assert!(!secrets.is_match("project/api/public/readme"));
+///
+/// let images = GlobPattern::new("*.[jp][pn]g").unwrap().case_insensitive(true);
+///
+/// assert!(images.is_match("Photo.PNG"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobPattern {
+    pattern: String,
+    tokens: Vec<Token>,
+    folded: Option<Vec<Token>>,
+}
+
+impl GlobPattern {
+    /// Compiles `pattern`, the matching is case-sensitive
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseGlobError`] when a `[` is never closed or when the pattern ends with a
+    /// backslash
+    pub fn new(pattern: &str) -> Result<Self, ParseGlobError> {
+        let tokens = compile(pattern).map_err(|(kind, byte_offset)| ParseGlobError {
+            kind,
+            byte_offset,
+            char_offset: pattern[..byte_offset].chars().count(),
+        })?;
+        Ok(Self {
+            pattern: pattern.to_string(),
+            tokens,
+            folded: None,
+        })
+    }
+
+    /// Sets if the case is ignored, both the pattern and the strings are compared once case
+    /// folded like [`LocaleCase::fold_case`] does, while `?`, `*` and classes always take
+    /// whole characters of the string so `?` matches `ß`
+    #[must_use]
+    pub fn case_insensitive(mut self, case_insensitive: bool) -> Self {
+        self.folded = case_insensitive.then(|| fold_tokens(&self.tokens));
+        self
+    }
+
+    /// Returns the pattern this was compiled from
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Checks if the whole of `input` matches the pattern
+    #[must_use]
+    pub fn is_match(&self, input: &str) -> bool {
+        match &self.folded {
+            Some(folded) => is_match(folded, input, true),
+            None => is_match(&self.tokens, input, false),
+        }
+    }
+}
+
+impl fmt::Display for GlobPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+impl FromStr for GlobPattern {
+    type Err = ParseGlobError;
+
+    fn from_str(pattern: &str) -> Result<Self, Self::Err> {
+        Self::new(pattern)
+    }
+}
+
+/// Declares methods that match strings such as paths or resource names against glob patterns,
+/// see [`GlobPattern`] for the syntax
+///
+/// The pattern is compiled on every call, compile it once with [`GlobPattern::new`] to match
+/// many strings
+pub trait MatchesGlob {
+    /// Checks if the whole string matches the glob `pattern`, an invalid pattern matches
+    /// nothing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::MatchesGlob;
+    ///
+    /// assert!("src/strings/methods.rs".matches_glob("src/**/*.rs"));
+    /// assert!("file7.txt".matches_glob("file[0-9].txt"));
+    /// assert!(!"src/main.rs".matches_glob("*.rs"));
+    ///```
+    #[must_use]
+    fn matches_glob(&self, pattern: &str) -> bool;
+
+    /// Checks if the whole string matches the glob `pattern` ignoring the case, an invalid
+    /// pattern matches nothing
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crate::strings::methods::MatchesGlob;
+    ///
+    /// assert!("README.MD".matches_glob_ignore_case("readme.*"));
+    ///```
+    #[must_use]
+    fn matches_glob_ignore_case(&self, pattern: &str) -> bool;
+}
+
+impl<T: AsRef<str> + ?Sized> SelectNth for T {
+    fn first(&self) -> &str {
+        graphemes(self.as_ref()).next().unwrap_or_default()
+    }
+
+    fn nth(&self, index: usize) -> Option<&str> {
+        graphemes(self.as_ref()).nth(index)
+    }
+
+    fn last(&self) -> Option<&str> {
+        graphemes(self.as_ref()).last()
+    }
+
+    fn first_n(&self, count: usize) -> &str {
+        let input = self.as_ref();
+        let end = graphemes(input).take(count).map(str::len).sum();
+        &input[..end]
+    }
+
+    fn last_n(&self, count: usize) -> &str {
+        let input = self.as_ref();
+        let skipped = graphemes(input).count().saturating_sub(count);
+        let start = graphemes(input).take(skipped).map(str::len).sum();
+        &input[start..]
+    }
+
+    fn char_slice(&self, range: impl RangeBounds<usize>) -> Option<&str> {
+        let input = self.as_ref();
+
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.checked_add(1)?,
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => graphemes(input).count(),
+        };
+
+        if start > end {
+            return None;
+        }
+
+        let start_offset = grapheme_offset(input, start)?;
+        let end_offset = start_offset + grapheme_offset(&input[start_offset..], end - start)?;
+        Some(&input[start_offset..end_offset])
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> RemoveWhitespaces for T {
+    fn remove_whitespaces(&self) -> String {
+        let input = self.as_ref();
+        let mut new_string_with_no_white_spaces = String::with_capacity(input.len());
+        for character in input.chars() {
+            if !character.is_whitespace() {
+                new_string_with_no_white_spaces.push(character);
+            }
+        }
+        new_string_with_no_white_spaces
+    }
 
-        let firs_word = words.next().unwrap_or_default().to_lowercase();
+    fn remove_whitespaces_except_newlines(&self) -> String {
+        self.as_ref()
+            .chars()
+            .filter(|character| !character.is_whitespace() || matches!(character, '\n' | '\r'))
+            .collect()
+    }
+
+    fn collapse_whitespace(&self) -> String {
+        let input = self.as_ref();
+        let mut collapsed = String::with_capacity(input.len());
+        let mut in_whitespace = false;
+        for character in input.chars() {
+            if character.is_whitespace() {
+                if !in_whitespace {
+                    collapsed.push(' ');
+                }
+                in_whitespace = true;
+            } else {
+                collapsed.push(character);
+                in_whitespace = false;
+            }
+        }
+        collapsed
+    }
+
+    fn trim_lines(&self) -> String {
+        let input = self.as_ref();
+        let mut trimmed = String::with_capacity(input.len());
+        for line in input.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            trimmed.push_str(content.trim());
+            trimmed.push_str(&line[content.len()..]);
+        }
+        trimmed
+    }
 
-        let result = words.fold(
-            String::with_capacity(self.capacity()),
-            |mut output, current| {
-                let first_character = current.first().to_uppercase();
-                let rest_word = &current[1..current.len()];
-                let _ = write!(output, "{first_character}{rest_word}");
-                output
-            },
-        );
+    fn normalize_line_endings(&self) -> String {
+        let input = self.as_ref();
+        let mut normalized = String::with_capacity(input.len());
+        let mut characters = input.chars().peekable();
+        while let Some(character) = characters.next() {
+            if character == '\r' {
+                characters.next_if_eq(&'\n');
+                normalized.push('\n');
+            } else {
+                normalized.push(character);
+            }
+        }
+        normalized
+    }
+
+    fn strip_zero_width(&self) -> String {
+        self.as_ref()
+            .chars()
+            .filter(|character| {
+                !matches!(
+                    character,
+                    '\u{200B}' | '\u{200C}' | '\u{200D}' | '\u{2060}' | '\u{FEFF}'
+                )
+            })
+            .collect()
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> ToCases for T {
+    fn to_snake_case(&self) -> String {
+        self.convert_case(Case::Snake)
+    }
 
-        format!("{firs_word}{result}")
+    fn to_camel_case(&self) -> String {
+        self.convert_case(Case::Camel)
     }
 
     fn to_pascal_case(&self) -> String {
-        self.split_whitespace().fold(
-            String::with_capacity(self.capacity()),
-            |mut output, current| {
-                let first_character = current.first().to_uppercase();
-                let rest_word = &current[1..current.len()];
-                let _ = write!(output, "{first_character}{rest_word}");
-                output
-            },
-        )
+        self.convert_case(Case::Pascal)
+    }
+
+    fn to_kebab_case(&self) -> String {
+        self.convert_case(Case::Kebab)
+    }
+
+    fn to_screaming_snake_case(&self) -> String {
+        self.convert_case(Case::ScreamingSnake)
+    }
+
+    fn to_train_case(&self) -> String {
+        self.convert_case(Case::Train)
+    }
+
+    fn to_dot_case(&self) -> String {
+        self.convert_case(Case::Dot)
+    }
+
+    fn to_path_case(&self) -> String {
+        self.convert_case(Case::Path)
+    }
+
+    fn to_title_case(&self) -> String {
+        self.convert_case(Case::Title)
+    }
+
+    fn to_sentence_case(&self) -> String {
+        self.convert_case(Case::Sentence)
+    }
+
+    fn convert_case_with(&self, case: Case, options: &CaseOptions) -> String {
+        let input = self.as_ref();
+        let mut output = String::with_capacity(input.len());
+        let _ = self.write_case_with(case, options, &mut output);
+        output
+    }
+
+    fn write_case_with(
+        &self,
+        case: Case,
+        options: &CaseOptions,
+        output: &mut impl fmt::Write,
+    ) -> fmt::Result {
+        let (separator, first, rest) = case.layout();
+        write_words(output, self.as_ref(), separator, first, rest, options)
+    }
+
+    fn as_case_with<'a>(&'a self, case: Case, options: &'a CaseOptions) -> CaseDisplay<'a> {
+        CaseDisplay {
+            input: self.as_ref(),
+            case,
+            options,
+        }
     }
 }
 
-impl IsCases for String {
+impl<T: AsRef<str> + ?Sized> IsCases for T {
     fn is_camel_case(&self) -> bool {
-        if *self == self.to_lowercase() {
-            return false;
+        self.is_case(Case::Camel, DigitRule::default())
+    }
+
+    fn is_pascal_case(&self) -> bool {
+        self.is_case(Case::Pascal, DigitRule::default())
+    }
+
+    fn is_snake_case(&self) -> bool {
+        self.is_case(Case::Snake, DigitRule::default())
+    }
+
+    fn is_kebab_case(&self) -> bool {
+        self.is_case(Case::Kebab, DigitRule::default())
+    }
+
+    fn is_screaming_snake_case(&self) -> bool {
+        self.is_case(Case::ScreamingSnake, DigitRule::default())
+    }
+
+    fn is_train_case(&self) -> bool {
+        self.is_case(Case::Train, DigitRule::default())
+    }
+
+    fn is_case(&self, case: Case, digits: DigitRule) -> bool {
+        check_case(self.as_ref(), case, digits).is_ok()
+    }
+
+    fn validate_case(&self, case: Case) -> Result<(), CaseViolation> {
+        let input = self.as_ref();
+        check_case(input, case, DigitRule::default()).map_err(|(rule, byte_offset)| CaseViolation {
+            case,
+            rule,
+            byte_offset,
+            char_offset: input[..byte_offset].chars().count(),
+            suggestion: input.convert_case(case),
+        })
+    }
+
+    fn detect_case(&self) -> Option<Case> {
+        Case::ALL
+            .into_iter()
+            .find(|case| self.is_case(*case, DigitRule::default()))
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> ToIdentifier for T {
+    fn to_identifier_with(&self, language: Language, case: Case) -> String {
+        to_identifier(self.as_ref(), language, case)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Inflect for T {
+    fn pluralize(&self) -> String {
+        inflect(self.as_ref(), Number::Plural)
+    }
+
+    fn singularize(&self) -> String {
+        inflect(self.as_ref(), Number::Singular)
+    }
+
+    fn ordinalize(&self) -> String {
+        let input = self.as_ref();
+        let digits = input.len()
+            - input
+                .trim_end_matches(|character: char| character.is_ascii_digit())
+                .len();
+
+        if digits == 0 {
+            return input.to_string();
+        }
+
+        // only the last two digits decide the suffix, so very long numbers cannot overflow
+        let last_digits = &input[input.len() - digits.min(2)..];
+        let suffix = ordinal_suffix(last_digits.parse().unwrap_or_default());
+        format!("{input}{suffix}")
+    }
+
+    fn tableize(&self) -> String {
+        self.to_snake_case().pluralize()
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> ToSlug for T {
+    fn to_slug(&self) -> String {
+        let input = self.as_ref();
+        let mut ascii = String::with_capacity(input.len());
+        for character in input.chars().flat_map(char::to_lowercase) {
+            if matches!(character, '\'' | '’' | 'ʼ') || is_combining_mark(character) {
+                continue;
+            }
+
+            if character.is_ascii() {
+                ascii.push(character);
+            } else if let Some(transliterated) = transliterate(character) {
+                ascii.push_str(transliterated);
+            } else if !character.is_alphanumeric() {
+                ascii.push(' ');
+            }
         }
-        if self.first() == self.first().to_uppercase() {
-            return false;
+        ascii.to_kebab_case()
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> DisplayWidth for T {
+    fn display_width(&self) -> usize {
+        graphemes(self.as_ref()).map(grapheme_width).sum()
+    }
+
+    fn truncate_to_width(&self, width: usize, ellipsis: &str) -> String {
+        let input = self.as_ref();
+        if input.display_width() <= width {
+            return input.to_string();
         }
 
-        if self.contains(|ch: char| !ch.is_alphabetic()) {
-            return false;
+        let ellipsis = if ellipsis.display_width() > width {
+            ""
+        } else {
+            ellipsis
+        };
+
+        let available = width - ellipsis.display_width();
+        let mut used = 0;
+        let mut truncated = String::with_capacity(input.len());
+        for grapheme in graphemes(input) {
+            used += grapheme_width(grapheme);
+            if used > available {
+                break;
+            }
+            truncated.push_str(grapheme);
         }
+        truncated.push_str(ellipsis);
+        truncated
+    }
 
-        true
+    fn pad_left_to_width(&self, width: usize) -> String {
+        let input = self.as_ref();
+        let padding = width.saturating_sub(input.display_width());
+        format!("{}{input}", " ".repeat(padding))
     }
 
-    fn is_pascal_case(&self) -> bool {
-        if *self == self.to_lowercase() {
-            return false;
+    fn pad_right_to_width(&self, width: usize) -> String {
+        let input = self.as_ref();
+        let padding = width.saturating_sub(input.display_width());
+        format!("{input}{}", " ".repeat(padding))
+    }
+
+    fn center(&self, width: usize, fill: char) -> String {
+        let input = self.as_ref();
+        let padding = width.saturating_sub(input.display_width());
+        let fill_width = fill.encode_utf8(&mut [0; 4]).display_width().max(1);
+
+        let fill_columns = |columns: usize| {
+            let mut filled = String::with_capacity(columns);
+            filled.extend(std::iter::repeat_n(fill, columns / fill_width));
+            filled.extend(std::iter::repeat_n(' ', columns % fill_width));
+            filled
+        };
+
+        let left = padding / 2;
+        format!(
+            "{}{input}{}",
+            fill_columns(left),
+            fill_columns(padding - left)
+        )
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Wrap for T {
+    fn wrap_with(&self, options: &WrapOptions) -> Vec<String> {
+        wrap(self.as_ref(), options)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Indent for T {
+    fn indent_with<P: AsRef<str>>(&self, mut prefix: impl FnMut(usize) -> P) -> String {
+        let input = self.as_ref();
+        let mut indented = String::with_capacity(input.len());
+        for (number, line) in input.split_inclusive('\n').enumerate() {
+            if !line.trim().is_empty() {
+                indented.push_str(prefix(number).as_ref());
+            }
+            indented.push_str(line);
         }
-        if self.first() != self.first().to_uppercase() {
-            return false;
+        indented
+    }
+
+    fn dedent(&self) -> String {
+        let input = self.as_ref();
+        let is_indentation = |character: char| matches!(character, ' ' | '\t');
+
+        let margin = input
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| &line[..line.len() - line.trim_start_matches(is_indentation).len()])
+            .reduce(|margin, indentation| {
+                let common = margin
+                    .bytes()
+                    .zip(indentation.bytes())
+                    .take_while(|(left, right)| left == right)
+                    .count();
+                &margin[..common]
+            })
+            .unwrap_or_default();
+
+        let mut dedented = String::with_capacity(input.len());
+        for line in input.split_inclusive('\n') {
+            let content = line.trim_end_matches(['\n', '\r']);
+            if !content.trim().is_empty() {
+                dedented.push_str(&content[margin.len()..]);
+            }
+            dedented.push_str(&line[content.len()..]);
         }
+        dedented
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Similarity for T {
+    fn levenshtein(&self, other: &str) -> usize {
+        levenshtein(self.as_ref(), other)
+    }
+
+    fn damerau_levenshtein(&self, other: &str) -> usize {
+        damerau_levenshtein(self.as_ref(), other)
+    }
+
+    fn jaro_winkler(&self, other: &str) -> f64 {
+        jaro_winkler(self.as_ref(), other)
+    }
 
-        if self.contains(|ch: char| !ch.is_alphabetic()) {
-            return false;
+    fn normalized_similarity(&self, other: &str) -> f64 {
+        normalized_similarity(self.as_ref(), other)
+    }
+
+    fn closest_match<'c, C>(
+        &self,
+        candidates: impl IntoIterator<Item = &'c C>,
+        threshold: f64,
+    ) -> Vec<&'c str>
+    where
+        C: AsRef<str> + ?Sized + 'c,
+    {
+        let input = self.as_ref();
+        let mut scored: Vec<(f64, &str)> = candidates
+            .into_iter()
+            .map(|candidate| {
+                let candidate = candidate.as_ref();
+                (normalized_similarity(input, candidate), candidate)
+            })
+            .filter(|(score, _)| *score >= threshold)
+            .collect();
+        scored.sort_by(|(left, _), (right, _)| right.total_cmp(left));
+        scored
+            .into_iter()
+            .take(MAX_SUGGESTIONS)
+            .map(|(_, candidate)| candidate)
+            .collect()
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> NaturalCmp for T {
+    fn natural_cmp(&self, other: &str) -> Ordering {
+        natural_cmp(self.as_ref().chars(), other.chars())
+    }
+
+    fn natural_cmp_ignore_case(&self, other: &str) -> Ordering {
+        natural_cmp(
+            self.as_ref().chars().flat_map(char::to_lowercase),
+            other.chars().flat_map(char::to_lowercase),
+        )
+    }
+
+    fn natural_cmp_folded(&self, other: &str) -> Ordering {
+        natural_cmp(
+            self.as_ref().chars().flat_map(fold_case),
+            other.chars().flat_map(fold_case),
+        )
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> FuzzySearch for T {
+    fn fuzzy_match(&self, pattern: &str) -> Option<FuzzyMatch> {
+        fuzzy_match(self.as_ref(), pattern)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> LocaleCase for T {
+    fn fold_case_in(&self, locale: Locale) -> String {
+        self.as_ref()
+            .chars()
+            .flat_map(|character| fold_case_in(character, locale))
+            .collect()
+    }
+
+    fn eq_ignore_case_in(&self, other: &str, locale: Locale) -> bool {
+        let fold = |character| fold_case_in(character, locale);
+        self.as_ref()
+            .chars()
+            .flat_map(fold)
+            .eq(other.chars().flat_map(fold))
+    }
+
+    fn to_lowercase_in(&self, locale: Locale) -> String {
+        let input = self.as_ref();
+        let mut output = String::with_capacity(input.len());
+        let _ = write_lowercase(&mut output, input, None, locale);
+        output
+    }
+
+    fn to_uppercase_in(&self, locale: Locale) -> String {
+        let input = self.as_ref();
+        let mut output = String::with_capacity(input.len());
+        let _ = write_uppercase(&mut output, input, locale);
+        output
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> Escape for T {
+    fn escape_json(&self) -> String {
+        escape_json(self.as_ref())
+    }
+
+    fn unescape_json(&self) -> Result<String, UnescapeError> {
+        let input = self.as_ref();
+        with_position(input, unescape_json(input))
+    }
+
+    fn escape_c(&self) -> String {
+        escape_c(self.as_ref())
+    }
+
+    fn unescape_c(&self) -> Result<String, UnescapeError> {
+        let input = self.as_ref();
+        with_position(input, unescape_c(input))
+    }
+
+    fn escape_html(&self) -> String {
+        escape_html(self.as_ref())
+    }
+
+    fn unescape_html(&self) -> Result<String, UnescapeError> {
+        let input = self.as_ref();
+        with_position(input, unescape_html(input))
+    }
+
+    fn escape_xml(&self) -> String {
+        escape_xml(self.as_ref())
+    }
+
+    fn unescape_xml(&self) -> Result<String, UnescapeError> {
+        let input = self.as_ref();
+        with_position(input, unescape_xml(input))
+    }
+
+    fn shell_quote(&self) -> String {
+        shell_quote(self.as_ref())
+    }
+
+    fn unescape_shell(&self) -> Result<String, UnescapeError> {
+        let input = self.as_ref();
+        with_position(input, unescape_shell(input))
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> SplitQuoted for T {
+    fn split_shell_words(&self) -> Result<Vec<String>, UnescapeError> {
+        let input = self.as_ref();
+        with_position(input, split_shell_words(input))
+    }
+
+    fn split_quoted(
+        &self,
+        delimiter: char,
+        quote: char,
+        escape: char,
+    ) -> Result<Vec<String>, UnescapeError> {
+        let input = self.as_ref();
+        with_position(input, split_quoted(input, delimiter, quote, escape))
+    }
+}
+
+impl<S: AsRef<str>> JoinShellWords for [S] {
+    fn join_shell_words(&self) -> String {
+        let quoted: Vec<String> = self.iter().map(|word| shell_quote(word.as_ref())).collect();
+        quoted.join(" ")
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> FormatNamed for T {
+    fn format_named(&self, values: &impl Lookup) -> String {
+        format_named(self.as_ref(), values, &mut Vec::new())
+    }
+
+    fn format_named_strict(&self, values: &impl Lookup) -> Result<String, MissingKeysError> {
+        let template = self.as_ref();
+        let mut missing = Vec::new();
+        let formatted = format_named(template, values, &mut missing);
+        if missing.is_empty() {
+            return Ok(formatted);
         }
 
-        true
+        let missing = missing
+            .into_iter()
+            .map(|(name, byte_offset)| MissingKey {
+                name,
+                byte_offset,
+                char_offset: template[..byte_offset].chars().count(),
+            })
+            .collect();
+        Err(MissingKeysError { missing })
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> MatchesGlob for T {
+    fn matches_glob(&self, pattern: &str) -> bool {
+        GlobPattern::new(pattern).is_ok_and(|glob| glob.is_match(self.as_ref()))
     }
+
+    fn matches_glob_ignore_case(&self, pattern: &str) -> bool {
+        GlobPattern::new(pattern)
+            .is_ok_and(|glob| glob.case_insensitive(true).is_match(self.as_ref()))
+    }
+}
+
+/// Turns the byte offset in `input` of an error of the escaping and splitting functions into
+/// an [`UnescapeError`]
+fn with_position<T>(
+    input: &str,
+    result: Result<T, (UnescapeErrorKind, usize)>,
+) -> Result<T, UnescapeError> {
+    result.map_err(|(kind, byte_offset)| UnescapeError {
+        kind,
+        byte_offset,
+        char_offset: input[..byte_offset].chars().count(),
+    })
 }
@@ -0,0 +1,70 @@
+//! Natural ordering of strings, where the numbers embedded in the text compare by their value
+
+use std::cmp::Ordering;
+use std::iter::Peekable;
+
+/// Compares two sequences of characters, runs of ASCII digits are compared by their numeric
+/// value whatever their length and every other character by its code point
+///
+/// Leading zeros only decide the order when everything else is equal, then the first number
+/// with fewer leading zeros sorts first, so the ordering stays total and `"a1"` comes before
+/// `"a01"`
+pub(crate) fn natural_cmp(
+    left: impl Iterator<Item = char>,
+    right: impl Iterator<Item = char>,
+) -> Ordering {
+    let mut left = left.peekable();
+    let mut right = right.peekable();
+    let mut zeros = Ordering::Equal;
+
+    loop {
+        match (left.peek(), right.peek()) {
+            (None, None) => return zeros,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(l), Some(r)) if l.is_ascii_digit() && r.is_ascii_digit() => {
+                let left_zeros = skip_zeros(&mut left);
+                let right_zeros = skip_zeros(&mut right);
+                match compare_numbers(&mut left, &mut right) {
+                    Ordering::Equal => zeros = zeros.then(left_zeros.cmp(&right_zeros)),
+                    ordering => return ordering,
+                }
+            }
+            (Some(l), Some(r)) => match l.cmp(r) {
+                Ordering::Equal => {
+                    left.next();
+                    right.next();
+                }
+                ordering => return ordering,
+            },
+        }
+    }
+}
+
+/// Consumes the leading zeros of a number and returns how many there were
+fn skip_zeros(digits: &mut Peekable<impl Iterator<Item = char>>) -> usize {
+    let mut count = 0;
+    while digits.next_if_eq(&'0').is_some() {
+        count += 1;
+    }
+    count
+}
+
+/// Consumes two runs of digits without their leading zeros, the longest is the greatest and
+/// runs of the same length compare by their first different digit
+fn compare_numbers(
+    left: &mut Peekable<impl Iterator<Item = char>>,
+    right: &mut Peekable<impl Iterator<Item = char>>,
+) -> Ordering {
+    let mut first_difference = Ordering::Equal;
+    loop {
+        let l = left.next_if(char::is_ascii_digit);
+        let r = right.next_if(char::is_ascii_digit);
+        match (l, r) {
+            (Some(l), Some(r)) => first_difference = first_difference.then(l.cmp(&r)),
+            (Some(_), None) => return Ordering::Greater,
+            (None, Some(_)) => return Ordering::Less,
+            (None, None) => return first_difference,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Edit distances and similarity scores between strings, counted in `char`s
+
+/// Returns the number of insertions, deletions and substitutions of characters needed to turn
+/// `left` into `right`
+pub(crate) fn levenshtein(left: &str, right: &str) -> usize {
+    let right: Vec<char> = right.chars().collect();
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    let mut current = vec![0; right.len() + 1];
+
+    for (i, left_char) in left.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, &right_char) in right.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left_char != right_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[right.len()]
+}
+
+/// Returns the Levenshtein distance where swapping two adjacent characters also counts as a
+/// single edit, each substring can only be edited once (optimal string alignment)
+pub(crate) fn damerau_levenshtein(left: &str, right: &str) -> usize {
+    let left: Vec<char> = left.chars().collect();
+    let right: Vec<char> = right.chars().collect();
+    let mut before_previous = vec![0; right.len() + 1];
+    let mut previous: Vec<usize> = (0..=right.len()).collect();
+    let mut current = vec![0; right.len() + 1];
+
+    for i in 0..left.len() {
+        current[0] = i + 1;
+        for j in 0..right.len() {
+            let substitution = previous[j] + usize::from(left[i] != right[j]);
+            let mut distance = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+            if i > 0 && j > 0 && left[i] == right[j - 1] && left[i - 1] == right[j] {
+                distance = distance.min(before_previous[j - 1] + 1);
+            }
+            current[j + 1] = distance;
+        }
+        std::mem::swap(&mut before_previous, &mut previous);
+        std::mem::swap(&mut previous, &mut current);
+    }
+    previous[right.len()]
+}
+
+/// Returns the Jaro similarity boosted by up to 4 common leading characters, from 0.0 for
+/// strings that have nothing in common to 1.0 for equal strings
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn jaro_winkler(left: &str, right: &str) -> f64 {
+    let left: Vec<char> = left.chars().collect();
+    let right: Vec<char> = right.chars().collect();
+    if left.is_empty() && right.is_empty() {
+        return 1.0;
+    }
+    if left.is_empty() || right.is_empty() {
+        return 0.0;
+    }
+
+    let window = (left.len().max(right.len()) / 2).saturating_sub(1);
+    let mut left_matched = vec![false; left.len()];
+    let mut right_matched = vec![false; right.len()];
+    let mut matches = 0_usize;
+    for (i, &character) in left.iter().enumerate() {
+        let end = (i + window + 1).min(right.len());
+        for j in i.saturating_sub(window)..end {
+            if !right_matched[j] && right[j] == character {
+                left_matched[i] = true;
+                right_matched[j] = true;
+                matches += 1;
+                break;
+            }
+        }
+    }
+    if matches == 0 {
+        return 0.0;
+    }
+
+    let left_in_order = left.iter().zip(&left_matched).filter(|(_, &m)| m);
+    let right_in_order = right.iter().zip(&right_matched).filter(|(_, &m)| m);
+    let transpositions = left_in_order
+        .zip(right_in_order)
+        .filter(|((l, _), (r, _))| l != r)
+        .count()
+        / 2;
+
+    let matches = matches as f64;
+    let jaro = (matches / left.len() as f64
+        + matches / right.len() as f64
+        + (matches - transpositions as f64) / matches)
+        / 3.0;
+
+    let prefix = left
+        .iter()
+        .zip(&right)
+        .take(4)
+        .take_while(|(l, r)| l == r)
+        .count();
+    jaro + prefix as f64 * 0.1 * (1.0 - jaro)
+}
+
+/// Returns `1.0` minus the Damerau-Levenshtein distance divided by the length of the longest
+/// string, two empty strings are equal
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn normalized_similarity(left: &str, right: &str) -> f64 {
+    let longest = left.chars().count().max(right.chars().count());
+    if longest == 0 {
+        return 1.0;
+    }
+    1.0 - damerau_levenshtein(left, right) as f64 / longest as f64
+}
@@ -0,0 +1,117 @@
+//! Splitting of strings into words or fields that respects quotes and escapes
+
+use super::methods::UnescapeErrorKind;
+
+/// The result of the splitting functions, the error holds the byte offset where the problem is
+type Split = Result<Vec<String>, (UnescapeErrorKind, usize)>;
+
+/// Splits `input` into words like a POSIX shell does, without expanding anything
+///
+/// Words are separated by unquoted whitespace, single quotes keep everything as it is, double
+/// quotes only let a backslash escape `$`, `` ` ``, `"`, `\` and a newline, and outside quotes a
+/// backslash escapes any character, a backslash before a newline joins the lines
+///
+/// An unquoted `#` that starts a word starts a comment that runs until the end of the line, a
+/// `#` inside a word such as `a#b` is kept
+///
+/// # Errors
+///
+/// Returns the offset of a quote that is never closed or of a trailing backslash
+pub(crate) fn split_shell_words(input: &str) -> Split {
+    let mut words = Vec::new();
+    let mut word: Option<String> = None;
+    let mut characters = input.char_indices();
+    while let Some((offset, character)) = characters.next() {
+        if character.is_whitespace() {
+            words.extend(word.take());
+            continue;
+        }
+
+        let started = word.is_some();
+        if character == '#' && !started {
+            characters.find(|&(_, commented)| commented == '\n');
+            continue;
+        }
+
+        let current = word.get_or_insert_with(String::new);
+        match character {
+            '\'' => loop {
+                match characters.next() {
+                    Some((_, '\'')) => break,
+                    Some((_, quoted)) => current.push(quoted),
+                    None => return Err((UnescapeErrorKind::UnterminatedQuote, offset)),
+                }
+            },
+            '"' => loop {
+                match characters.next() {
+                    Some((_, '"')) => break,
+                    Some((_, '\\')) => match characters.next() {
+                        Some((_, '\n')) => {}
+                        Some((_, escaped @ ('$' | '`' | '"' | '\\'))) => current.push(escaped),
+                        Some((_, other)) => {
+                            current.push('\\');
+                            current.push(other);
+                        }
+                        None => return Err((UnescapeErrorKind::UnterminatedQuote, offset)),
+                    },
+                    Some((_, quoted)) => current.push(quoted),
+                    None => return Err((UnescapeErrorKind::UnterminatedQuote, offset)),
+                }
+            },
+            '\\' => match characters.next() {
+                Some((_, '\n')) => {
+                    if !started {
+                        word = None;
+                    }
+                }
+                Some((_, escaped)) => current.push(escaped),
+                None => return Err((UnescapeErrorKind::TruncatedEscape, offset)),
+            },
+            _ => current.push(character),
+        }
+    }
+    words.extend(word);
+    Ok(words)
+}
+
+/// Splits `input` into the fields separated by `delimiter`, a delimiter between two `quote`
+/// characters is part of the field and `escape` makes the next character literal
+///
+/// When `escape` is the same character as `quote`, a doubled quote inside quotes is a literal
+/// quote, like in CSV
+///
+/// # Errors
+///
+/// Returns the offset of a quote that is never closed or of a trailing escape character
+pub(crate) fn split_quoted(input: &str, delimiter: char, quote: char, escape: char) -> Split {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut opening_quote = None;
+    let mut characters = input.char_indices().peekable();
+    while let Some((offset, character)) = characters.next() {
+        if opening_quote.is_some() && character == quote {
+            if escape == quote && characters.next_if(|&(_, next)| next == quote).is_some() {
+                field.push(quote);
+            } else {
+                opening_quote = None;
+            }
+        } else if character == quote {
+            opening_quote = Some(offset);
+        } else if character == escape {
+            let (_, escaped) = characters
+                .next()
+                .ok_or((UnescapeErrorKind::TruncatedEscape, offset))?;
+            field.push(escaped);
+        } else if character == delimiter && opening_quote.is_none() {
+            fields.push(std::mem::take(&mut field));
+        } else {
+            field.push(character);
+        }
+    }
+
+    if let Some(offset) = opening_quote {
+        return Err((UnescapeErrorKind::UnterminatedQuote, offset));
+    }
+    fields.push(field);
+    Ok(fields)
+}
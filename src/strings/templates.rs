@@ -0,0 +1,58 @@
+//! Interpolation of `{name}` placeholders in templates known only at runtime
+
+use super::methods::Lookup;
+
+/// Replaces the placeholders of `template` with the values of `values`
+///
+/// `{{` and `}}` are literal braces, `{name}` is replaced by the value of `name` and
+/// `{name:-fallback}` by the value of `name` or by `fallback` when there is none. A `{` that is
+/// never closed, a lone `}` and `{}` are kept as they are
+///
+/// The placeholders without a value nor a fallback are kept as they are, their names and byte
+/// offsets are pushed into `missing`
+pub(crate) fn format_named(
+    template: &str,
+    values: &impl Lookup,
+    missing: &mut Vec<(String, usize)>,
+) -> String {
+    let mut output = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(position) = rest.find(['{', '}']) {
+        output.push_str(&rest[..position]);
+        let offset = template.len() - rest.len() + position;
+        let tail = &rest[position..];
+
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            output.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+
+        let placeholder = tail
+            .strip_prefix('{')
+            .and_then(|inner| inner.find(['{', '}']).map(|end| &inner[..end]))
+            .filter(|inner| !inner.is_empty() && tail[inner.len() + 1..].starts_with('}'));
+        let Some(inner) = placeholder else {
+            output.push_str(&tail[..1]);
+            rest = &tail[1..];
+            continue;
+        };
+
+        let (name, fallback) = match inner.split_once(":-") {
+            Some((name, fallback)) => (name, Some(fallback)),
+            None => (inner, None),
+        };
+        let raw = &tail[..inner.len() + 2];
+        match (values.lookup(name), fallback) {
+            (Some(value), _) => output.push_str(&value),
+            (None, Some(fallback)) => output.push_str(fallback),
+            (None, None) => {
+                missing.push((name.to_string(), offset));
+                output.push_str(raw);
+            }
+        }
+        rest = &tail[raw.len()..];
+    }
+    output.push_str(rest);
+    output
+}
@@ -0,0 +1,144 @@
+//! The rules used to check if a string is in a given case
+
+use super::methods::{Case, CaseRule, DigitRule};
+use super::unicode::is_combining_mark;
+use super::words::WordCase;
+
+/// Checks if `input` is written in `case`, see [`crate::strings::methods::IsCases`] for the
+/// rules of every case
+///
+/// # Errors
+///
+/// Returns the first rule that `input` breaks together with the byte offset where it breaks it
+pub(crate) fn check_case(
+    input: &str,
+    case: Case,
+    digits: DigitRule,
+) -> Result<(), (CaseRule, usize)> {
+    if input.is_empty() {
+        return Err((CaseRule::Empty, 0));
+    }
+
+    match case.layout() {
+        (Some(separator), first, rest) => {
+            let mut word_start = 0;
+            for (index, word) in input.split(separator).enumerate() {
+                let word_case = if index == 0 { first } else { rest };
+                check_word(word, word_start, word_case, digits)?;
+                word_start += word.len() + separator.len_utf8();
+            }
+            Ok(())
+        }
+        (None, first, _) => check_joined(input, first, digits),
+    }
+}
+
+/// Checks a word that starts at the byte `word_start` of a case whose words are separated by a
+/// character, like `snake_case`
+fn check_word(
+    word: &str,
+    word_start: usize,
+    word_case: WordCase,
+    digits: DigitRule,
+) -> Result<(), (CaseRule, usize)> {
+    if word.is_empty() {
+        return Err((CaseRule::EmptyWord, word_start));
+    }
+
+    for (index, character) in word.char_indices() {
+        let offset = word_start + index;
+        check_character(
+            character,
+            offset,
+            digits,
+            word_start == 0 && index == 0,
+            index == 0,
+        )?;
+
+        if character.is_numeric() || is_combining_mark(character) {
+            continue;
+        }
+
+        match word_case {
+            WordCase::Upper if character.is_lowercase() => {
+                return Err((CaseRule::UnexpectedLowercase, offset));
+            }
+            WordCase::Capitalized if index == 0 && character.is_lowercase() => {
+                return Err((CaseRule::LeadingLowercase, offset));
+            }
+            WordCase::Lower | WordCase::Capitalized if index != 0 && character.is_uppercase() => {
+                return Err((CaseRule::UnexpectedUppercase, offset));
+            }
+            WordCase::Lower if character.is_uppercase() => {
+                return Err((CaseRule::LeadingUppercase, offset));
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks a case whose words are joined by camel humps, like `camelCase` and `PascalCase`
+fn check_joined(
+    input: &str,
+    first_word: WordCase,
+    digits: DigitRule,
+) -> Result<(), (CaseRule, usize)> {
+    for (index, character) in input.char_indices() {
+        check_character(character, index, digits, index == 0, index == 0)?;
+
+        if index != 0 || character.is_numeric() {
+            continue;
+        }
+
+        if first_word == WordCase::Lower && character.is_uppercase() {
+            return Err((CaseRule::LeadingUppercase, 0));
+        }
+
+        if first_word != WordCase::Lower && character.is_lowercase() {
+            return Err((CaseRule::LeadingLowercase, 0));
+        }
+    }
+
+    if !input.contains(char::is_uppercase) {
+        return Err((CaseRule::AllLowercase, 0));
+    }
+
+    Ok(())
+}
+
+/// Checks the rules every case shares, only letters and digits are allowed and digits must be
+/// where `digits` allows them
+fn check_character(
+    character: char,
+    offset: usize,
+    digits: DigitRule,
+    is_leading: bool,
+    is_word_start: bool,
+) -> Result<(), (CaseRule, usize)> {
+    if character.is_numeric() {
+        let allowed = match digits {
+            DigitRule::Forbidden => false,
+            DigitRule::NotLeading => !is_leading,
+            DigitRule::NotWordStart => !is_word_start,
+            DigitRule::Anywhere => true,
+        };
+
+        return if allowed {
+            Ok(())
+        } else {
+            Err((CaseRule::MisplacedDigit, offset))
+        };
+    }
+
+    if character.is_whitespace() {
+        return Err((CaseRule::Whitespace, offset));
+    }
+
+    if !character.is_alphabetic() && !is_combining_mark(character) {
+        return Err((CaseRule::IllegalCharacter(character), offset));
+    }
+
+    Ok(())
+}
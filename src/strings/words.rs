@@ -0,0 +1,265 @@
+//! Word segmentation shared by the case conversion methods
+
+use std::fmt;
+
+use super::locale::{special_lowercase, special_uppercase};
+use super::methods::{CapitalRuns, CaseOptions, Locale};
+use super::unicode::{is_combining_mark, to_titlecase};
+
+/// Iterator over the words of an identifier or a phrase
+///
+/// A new word starts:
+/// - after any character that is not alphanumeric, such as whitespace, `_`, `-`, `.` or `/`,
+///   the separators themselves are never part of a word
+/// - at a camel hump, meaning an uppercase character that follows a lowercase character or a
+///   digit, `parseHttp` becomes `parse` and `Http`
+/// - at the last uppercase character of a run of capitals when it is followed by a lowercase
+///   character, `HTTPServer` becomes `HTTP` and `Server`, or at every uppercase character when
+///   using [`CapitalRuns::EveryCapital`]
+/// - after a known acronym of [`CaseOptions`] inside a run of capitals, `XMLHTTPRequest` becomes
+///   `XML`, `HTTP` and `Request` when both `XML` and `HTTP` are known, as long as the rest of
+///   the run is long enough to be a word, so `IDS` is never split
+/// - after the plural of a known acronym written with a lowercase `s`, `userIDs` becomes `user`
+///   and `IDs` when `ID` is known
+///
+/// Digits stick to the word they touch, so `case2` and `Vec3` are single words while `Vec3Point`
+/// splits into `Vec3` and `Point`
+///
+/// Combining marks belong to the character before them, so a decomposed `e\u{301}` is never
+/// split and words are always cut at character boundaries
+pub(crate) struct Words<'a> {
+    input: &'a str,
+    position: usize,
+    options: &'a CaseOptions,
+}
+
+impl<'a> Iterator for Words<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.input[self.position..];
+        let start = rest.find(|ch: char| !is_separator(ch))?;
+        let word = &rest[start..];
+
+        if let Some(length) = self.plural_acronym(word) {
+            self.position += start + length;
+            return Some(&word[..length]);
+        }
+
+        let mut characters = word.char_indices().peekable();
+        let (_, mut previous) = characters.next()?;
+        let mut end = word.len();
+
+        while let Some((index, current)) = characters.next() {
+            if is_separator(current) {
+                end = index;
+                break;
+            }
+
+            if is_combining_mark(current) {
+                continue;
+            }
+
+            if current.is_uppercase() {
+                let next_is_lowercase = characters
+                    .peek()
+                    .is_some_and(|(_, next)| next.is_lowercase());
+
+                let splits_run =
+                    next_is_lowercase || self.options.capital_runs == CapitalRuns::EveryCapital;
+
+                let starts_plural = self.plural_acronym(&word[index..]).is_some();
+
+                if !previous.is_uppercase() || splits_run || starts_plural {
+                    end = index;
+                    break;
+                }
+            }
+
+            previous = current;
+        }
+
+        let word = &word[..end];
+        let word = self.leading_acronym(word).unwrap_or(word);
+
+        self.position += start + word.len();
+        Some(word)
+    }
+}
+
+impl<'a> Words<'a> {
+    /// Returns the longest known acronym `word` starts with, as long as the acronym is followed
+    /// by at least two more uppercase characters of the same run, which can start a word of
+    /// their own, and `word` is not a known acronym itself
+    ///
+    /// With `ID` known, `IDS` stays a single word since a lone `S` is not a word
+    fn leading_acronym(&self, word: &'a str) -> Option<&'a str> {
+        if self.options.is_acronym(word) {
+            return None;
+        }
+
+        self.options
+            .acronyms
+            .iter()
+            .filter(|acronym| {
+                word.starts_with(acronym.as_str())
+                    && word[acronym.len()..]
+                        .chars()
+                        .take_while(|character| character.is_uppercase())
+                        .count()
+                        > 1
+            })
+            .map(|acronym| &word[..acronym.len()])
+            .max_by_key(|acronym| acronym.len())
+    }
+
+    /// Returns the length of the plural of a known acronym `text` starts with, the acronym in
+    /// uppercase followed by a lowercase `s` that ends the word, such as `IDs` of `userIDs`
+    fn plural_acronym(&self, text: &str) -> Option<usize> {
+        self.options
+            .acronyms
+            .iter()
+            .filter(|acronym| {
+                let Some(after) = text
+                    .strip_prefix(acronym.as_str())
+                    .and_then(|rest| rest.strip_prefix('s'))
+                else {
+                    return false;
+                };
+                !after.starts_with(|next: char| next.is_lowercase() || is_combining_mark(next))
+            })
+            .map(|acronym| acronym.len() + 1)
+            .max()
+    }
+}
+
+/// Splits `input` into the words it is made of following `options`, see [`Words`] for the rules
+pub(crate) fn split_words<'a>(input: &'a str, options: &'a CaseOptions) -> Words<'a> {
+    Words {
+        input,
+        position: 0,
+        options,
+    }
+}
+
+fn is_separator(character: char) -> bool {
+    !character.is_alphanumeric() && !is_combining_mark(character)
+}
+
+/// Writes `word` into `output` with its first character in titlecase and the rest lowercased
+fn write_capitalized(output: &mut impl fmt::Write, word: &str, locale: Locale) -> fmt::Result {
+    let mut characters = word.chars();
+    if let Some(first) = characters.next() {
+        if let Some(special) = special_uppercase(locale, first, None) {
+            output.write_str(special)?;
+        } else {
+            for character in to_titlecase(first) {
+                output.write_char(character)?;
+            }
+        }
+        write_lowercase(output, characters.as_str(), Some(first), locale)?;
+    }
+    Ok(())
+}
+
+/// Writes `text` lowercased following the rules of `locale` into `output` without allocating,
+/// `previous` is the character that comes right before `text` in the word
+///
+/// `Σ` becomes the final sigma `ς` at the end of a word in every locale, like
+/// [`str::to_lowercase`] does
+pub(crate) fn write_lowercase(
+    output: &mut impl fmt::Write,
+    text: &str,
+    mut previous: Option<char>,
+    locale: Locale,
+) -> fmt::Result {
+    let mut characters = text.chars().peekable();
+    while let Some(character) = characters.next() {
+        let next = characters.peek().copied();
+        if let Some(special) = special_lowercase(locale, character, previous, next) {
+            output.write_str(special)?;
+        } else if character == 'Σ' {
+            let is_final =
+                previous.is_some_and(char::is_alphabetic) && !next.is_some_and(char::is_alphabetic);
+            output.write_char(if is_final { 'ς' } else { 'σ' })?;
+        } else {
+            for lowercased in character.to_lowercase() {
+                output.write_char(lowercased)?;
+            }
+        }
+        previous = Some(character);
+    }
+    Ok(())
+}
+
+/// Writes `text` uppercased following the rules of `locale` into `output` without allocating
+pub(crate) fn write_uppercase(
+    output: &mut impl fmt::Write,
+    text: &str,
+    locale: Locale,
+) -> fmt::Result {
+    let mut previous = None;
+    for character in text.chars() {
+        if let Some(special) = special_uppercase(locale, character, previous) {
+            output.write_str(special)?;
+        } else {
+            for uppercased in character.to_uppercase() {
+                output.write_char(uppercased)?;
+            }
+        }
+        previous = Some(character);
+    }
+    Ok(())
+}
+
+/// How the characters of a word are cased when joining words together
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WordCase {
+    Lower,
+    Upper,
+    Capitalized,
+}
+
+impl WordCase {
+    fn write(self, output: &mut impl fmt::Write, word: &str, options: &CaseOptions) -> fmt::Result {
+        match self {
+            WordCase::Lower => write_lowercase(output, word, None, options.locale),
+            WordCase::Upper => write_uppercase(output, word, options.locale),
+            WordCase::Capitalized if options.is_acronym(word) => {
+                write_uppercase(output, word, options.locale)
+            }
+            WordCase::Capitalized if options.is_acronym_plural(word) => {
+                write_uppercase(output, &word[..word.len() - 1], options.locale)?;
+                output.write_char('s')
+            }
+            WordCase::Capitalized => write_capitalized(output, word, options.locale),
+        }
+    }
+}
+
+/// Splits `input` into words and writes them back into `output` joined with `separator`, the
+/// first word is cased with `first` and every other word with `rest`
+///
+/// # Errors
+///
+/// Returns an error only if writing into `output` fails
+pub(crate) fn write_words(
+    output: &mut impl fmt::Write,
+    input: &str,
+    separator: Option<char>,
+    first: WordCase,
+    rest: WordCase,
+    options: &CaseOptions,
+) -> fmt::Result {
+    for (index, word) in split_words(input, options).enumerate() {
+        if index == 0 {
+            first.write(output, word, options)?;
+        } else {
+            if let Some(separator) = separator {
+                output.write_char(separator)?;
+            }
+            rest.write(output, word, options)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,87 @@
+//! Word wrapping of paragraphs
+
+use super::methods::{DisplayWidth, WrapOptions};
+use super::unicode::{grapheme_width, graphemes};
+
+/// Wraps every paragraph of `input` following `options`, see
+/// [`crate::strings::methods::Wrap`] for the rules
+pub(crate) fn wrap(input: &str, options: &WrapOptions) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut paragraph = String::new();
+
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            wrap_paragraph(&paragraph, options, &mut lines);
+            paragraph.clear();
+            lines.push(String::new());
+        } else {
+            paragraph.push_str(line);
+            paragraph.push(' ');
+        }
+    }
+    wrap_paragraph(&paragraph, options, &mut lines);
+
+    lines
+}
+
+fn wrap_paragraph(paragraph: &str, options: &WrapOptions, lines: &mut Vec<String>) {
+    let mut words = paragraph.split_whitespace().peekable();
+    if words.peek().is_none() {
+        return;
+    }
+
+    let mut line = Line::new(&options.initial_indent);
+    for word in words {
+        let word_width = word.display_width();
+
+        if !line.is_empty && line.width + 1 + word_width <= options.width {
+            line.push(" ", 1);
+            line.push(word, word_width);
+            continue;
+        }
+
+        if !line.is_empty {
+            lines.push(line.text);
+            line = Line::new(&options.subsequent_indent);
+        }
+
+        if line.width + word_width <= options.width {
+            line.push(word, word_width);
+            continue;
+        }
+
+        // the word is longer than a whole line, so it is broken between its characters
+        for grapheme in graphemes(word) {
+            let width = grapheme_width(grapheme);
+            if !line.is_empty && line.width + width > options.width {
+                lines.push(line.text);
+                line = Line::new(&options.subsequent_indent);
+            }
+            line.push(grapheme, width);
+        }
+    }
+    lines.push(line.text);
+}
+
+/// A line that is being filled with words
+struct Line {
+    text: String,
+    width: usize,
+    is_empty: bool,
+}
+
+impl Line {
+    fn new(indent: &str) -> Self {
+        Self {
+            text: indent.to_string(),
+            width: indent.display_width(),
+            is_empty: true,
+        }
+    }
+
+    fn push(&mut self, text: &str, width: usize) {
+        self.text.push_str(text);
+        self.width += width;
+        self.is_empty = false;
+    }
+}